@@ -24,6 +24,21 @@ pub use modules::html::*;
 /// of Jirai source code.
 pub use modules::lexer::*;
 
+/// Re-exporting the module containing
+/// entities to lazily tokenize Jirai
+/// source code from a reader.
+pub use modules::stream::*;
+
+/// Re-exporting the module containing
+/// entities to re-tokenize edited
+/// Jirai source code incrementally.
+pub use modules::incremental::*;
+
+/// Re-exporting the module containing
+/// entities to tokenize Jirai source
+/// code without losing any text.
+pub use modules::lossless::*;
+
 /// Re-exporting the module containing
 /// entities to parse a stream of
 /// tokens obtained from Jirai
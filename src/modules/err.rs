@@ -15,10 +15,18 @@ use std::fmt::Display;
 /// "Error" trait.
 use std::error::Error;
 
+/// Importing the structure
+/// for a range of byte offsets.
+use super::lexer::Span;
+
 /// Importing the standard
 /// "Formatter" trait.
 use std::fmt::Formatter;
 
+/// Importing the structure
+/// for a line/column position.
+use super::lexer::Position;
+
 /// A data structure for
 /// storing and handling errors.
 #[derive(Clone,Eq,PartialEq, Debug)]
@@ -40,13 +48,6 @@ impl JiraiErr {
             details: details.to_owned()
         }
     }
-
-    /// Implements a function to return
-    /// a string representation of this 
-    /// data structure.
-    pub fn to_string(self) -> String {
-        self.details.to_string()
-    }
 }
 
 /// Implements the `Error` trait.
@@ -66,3 +67,61 @@ impl Display for JiraiErr {
         write!(f,"{}",self.details)
     }
 }
+
+/// A data structure for
+/// storing a problem found
+/// in Jirai source code
+/// together with the location
+/// of the offending text.
+#[derive(Clone,PartialEq, Debug)]
+pub struct Diagnostic {
+    pub span: Span,
+    pub end: Position,
+    pub start: Position,
+    pub message: String
+}
+
+/// Implements functions
+/// for the `Diagnostic`
+/// structure.
+impl Diagnostic {
+
+    /// Implements a function to create
+    /// a new instance of this data structure.
+    pub fn new(
+        span: &Span,
+        end: &Position,
+        start: &Position,
+        message: &str
+    ) -> Diagnostic {
+        Diagnostic {
+            span: span.clone(),
+            end: end.clone(),
+            start: start.clone(),
+            message: message.to_owned()
+        }
+    }
+}
+
+/// Implements the Display trait
+/// for the `Diagnostic` structure.
+impl Display for Diagnostic {
+    fn fmt(
+        &self, 
+        f: &mut Formatter
+    ) -> Result {
+        write!(f,"{}",self.message)
+    }
+}
+
+/// Implements converting a
+/// `Diagnostic` into a `JiraiErr`
+/// so that it can be returned
+/// as a fatal error.
+impl From<Diagnostic> for JiraiErr {
+    fn from(
+        diagnostic: Diagnostic
+    ) -> JiraiErr {
+        JiraiErr::new(&diagnostic.message)
+    }
+}
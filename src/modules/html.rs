@@ -17,27 +17,91 @@ use super::parser::Image;
 /// to catch and handle errors.
 use super::err::JiraiErr;
 
+/// Importing the structure
+/// for a problem found in
+/// Jirai source code.
+use super::err::Diagnostic;
+
+/// Importing the structure
+/// encapsulating information
+/// on a parsed row of a table.
+use super::parser::TableRow;
+
+/// Importing the structure
+/// encapsulating information
+/// on a parsed list item.
+use super::parser::ListItem;
+
+/// Importing the structure
+/// encapsulating the location
+/// of a parsed statement.
+use super::parser::Location;
+
+/// Importing the enumeration
+/// listing the ways a column
+/// of a table can be aligned.
+use super::parser::Alignment;
+
 /// Importing the enumeration
 /// describing all possible types
 /// of block-level statements
 /// Jirai source code can contain.
 use super::parser::Statement;
 
+/// Importing the structure
+/// encapsulating information
+/// on a parsed term of a
+/// definition list.
+use super::parser::DefinitionItem;
+
 /// Importing the enumeration
 /// describing all possible types
 /// of inline statements Jirai source
 /// code can contain.
 use super::parser::InlineStatement;
 
+/// Importing the function to
+/// collect the footnote references
+/// of a parsed document.
+use super::parser::collect_footnote_references;
+
+/// Importing the function to
+/// collect the footnote definitions
+/// of a parsed document.
+use super::parser::collect_footnote_definitions;
+
 /// A structure holding the
 /// AST parsed and one to
 /// recursively generate HTML
-/// code.
+/// code. Comments are dropped
+/// unless the `emit_comments`
+/// field is set, in which case they
+/// are emitted as HTML comments.
+/// Dividers are emitted as `hr`
+/// elements unless the `divider_decoration`
+/// field is set, in which case they are
+/// emitted as decorative elements holding
+/// the text of that field. Footnotes are
+/// numbered in the order they are first
+/// referenced in, which the `footnotes` field
+/// keeps track of. The `footnote_totals` field
+/// holds the number of references to each
+/// footnote, counted before any code is generated,
+/// and the `footnote_references` field counts
+/// the references generated so far. Undefined and unused
+/// footnotes are collected in the
+/// `diagnostics` field.
 pub struct HTMLCodeGenerator{
     pub minify: bool,
     pub cursor: usize,
     pub alt_enforcing: bool,
-    pub statements: Vec<Statement>
+    pub emit_comments: bool,
+    pub footnotes: Vec<String>,
+    pub statements: Vec<Statement>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub divider_decoration: Option<String>,
+    pub footnote_totals: Vec<usize>,
+    pub footnote_references: Vec<usize>
 }
 
 /// Implementing functions
@@ -53,29 +117,61 @@ impl HTMLCodeGenerator{
     pub fn new(
         minify: &bool,
         alt_enforcing: &bool,
-        ast: &Vec<Statement>
+        ast: &[Statement]
     ) -> Result<HTMLCodeGenerator, JiraiErr>{
-        if ast.len() == 0{
-            return Err::<HTMLCodeGenerator, JiraiErr>(
+        if ast.is_empty(){
+            Err::<HTMLCodeGenerator, JiraiErr>(
                 JiraiErr::new("The AST cannot be empty.")
-            );
+            )
         }
         else {
             Ok(HTMLCodeGenerator{
                 cursor: 0,
                 minify: *minify,
                 alt_enforcing: *alt_enforcing,
-                statements: ast.to_vec()
+                emit_comments: false,
+                footnotes: Vec::new(),
+                statements: ast.to_vec(),
+                diagnostics: Vec::new(),
+                divider_decoration: None,
+                footnote_totals: Vec::new(),
+                footnote_references: Vec::new()
             })
         }
     }
 
+    /// A function to set whether comments
+    /// are emitted as HTML comments and return
+    /// the `HTMLCodeGenerator` structure, so that
+    /// the call can be chained onto the `new`
+    /// function.
+    pub fn with_comments(
+        mut self,
+        emit_comments: &bool
+    ) -> HTMLCodeGenerator {
+        self.emit_comments = *emit_comments;
+        self
+    }
+
+    /// A function to set the text dividers
+    /// are decorated with and return the
+    /// `HTMLCodeGenerator` structure, so that
+    /// the call can be chained onto the `new`
+    /// function.
+    pub fn with_divider_decoration(
+        mut self,
+        decoration: &str
+    ) -> HTMLCodeGenerator {
+        self.divider_decoration = Some(decoration.to_string());
+        self
+    }
+
     /// This function advances the
     /// internal cursor through the vector
     /// of statements constituting the AST.
     pub fn advance(
         &mut self
-    ) -> () {
+    ) {
         self.cursor += 1;
     }
 
@@ -86,7 +182,7 @@ impl HTMLCodeGenerator{
     pub fn is_done(
         &self
     ) -> bool {
-        &self.statements.len() == &self.cursor
+        self.statements.len() == self.cursor
     }
 
     /// This function attempts to retrieve the current
@@ -106,28 +202,61 @@ impl HTMLCodeGenerator{
 
     /// This function generates HTML code
     /// from the statements inside the AST
-    /// and returns it. If the operation fails,
-    /// an error is returned.
+    /// and returns it. Any footnotes are
+    /// collected into a section at the end.
+    /// If the operation fails, an error is
+    /// returned.
     pub fn generate(
         &mut self
     ) -> Result<String, JiraiErr> {
         let mut lines: Vec<String> = Vec::new();
+        let definitions: Vec<Statement> = self.number_footnotes();
         while !self.is_done(){
             let current: Statement = self.current()?;
-            match current {
-                Statement::Paragraph(i_statements) => lines.push(
-                    self.generate_paragraph_code(&i_statements)?),
-                Statement::UnorderedList(i_statements) => lines.push(
-                    self.generate_unordered_list_code(&i_statements)?),
-                Statement::Heading(level, i_statements) => lines.push(
-                    self.generate_heading_code(&level, &i_statements)?)
-            };
+            let code: String = self.generate_statement(&current)?;
+            if !code.is_empty(){
+                lines.push(code);
+            }
+            self.advance();
+        }
+        if !self.footnotes.is_empty(){
+            lines.push(self.generate_footnotes_code(&definitions)?);
         }
         if self.minify{
-            Ok(lines.join("\n"))
+            Ok(lines.into_iter().collect::<String>())
         }
         else {
-            Ok(lines.into_iter().collect::<String>())
+            Ok(lines.join("\n"))
+        }
+    }
+
+    /// The function to generate the HTML code
+    /// for a block element and nested elements
+    /// and return it. If the operation fails, an
+    /// error is returned.
+    pub fn generate_statement(
+        &mut self,
+        statement: &Statement
+    ) -> Result<String, JiraiErr> {
+        match statement {
+            Statement::Comment(_, comment) => Ok(self.generate_comment_code(comment)),
+            Statement::Paragraph(_, i_statements) => 
+                self.generate_paragraph_code(i_statements),
+            Statement::UnorderedList(_, items) => 
+                self.generate_unordered_list_code(items),
+            Statement::OrderedList(_, first, items) => 
+                self.generate_ordered_list_code(first, items),
+            Statement::BlockQuote(_, children, attribution) => 
+                self.generate_block_quote_code(children, attribution),
+            Statement::CodeBlock(_, language, code) => 
+                Ok(self.generate_code_block_code(language, code)),
+            Statement::Table(_, alignments, header, rows) => 
+                self.generate_table_code(alignments, header, rows),
+            Statement::Divider(_) => Ok(self.generate_divider_code()),
+            Statement::DefinitionList(_, items) => self.generate_definition_list_code(items),
+            Statement::FootnoteDefinition(_, _, _) => Ok(String::new()),
+            Statement::Heading(_, level, i_statements) => 
+                self.generate_heading_code(level, i_statements)
         }
     }
 
@@ -138,19 +267,18 @@ impl HTMLCodeGenerator{
     pub fn generate_heading_code(
         &mut self,
         level: &usize,
-        inline_statements: &Vec<InlineStatement>
+        inline_statements: &[InlineStatement]
     ) -> Result<String, JiraiErr> {
         let mut lines: Vec<String> = Vec::new();
         for i_statement in inline_statements{
-            lines.push(self.generate_inline_statement(&i_statement)?);
+            lines.push(self.generate_inline_statement(i_statement)?);
         }
-        let joined: String;
-        if self.minify{
-            joined = lines.into_iter().collect::<String>();
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
         }
         else {
-            joined = lines.join("\n");
-        }
+            lines.join("\n")
+        };
         Ok(format!("<h{}>{}</h{}>", level, joined, level))
     }
 
@@ -160,19 +288,21 @@ impl HTMLCodeGenerator{
     /// error is returned.
     pub fn generate_paragraph_code(
         &mut self,
-        inline_statements: &Vec<InlineStatement>
+        inline_statements: &[InlineStatement]
     ) -> Result<String, JiraiErr> {
         let mut lines: Vec<String> = Vec::new();
         for i_statement in inline_statements{
-            lines.push(self.generate_inline_statement(&i_statement)?);
+            if !self.minify && matches!(i_statement, InlineStatement::SoftBreak(_)){
+                continue;
+            }
+            lines.push(self.generate_inline_statement(i_statement)?);
         }
-        let joined: String;
-        if self.minify{
-            joined = lines.into_iter().collect::<String>();
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
         }
         else {
-            joined = lines.join("\n");
-        }
+            lines.join("\n")
+        };
         Ok(format!("<p>{}</p>", joined))
     }
 
@@ -182,22 +312,50 @@ impl HTMLCodeGenerator{
     /// error is returned.
     pub fn generate_unordered_list_code(
         &mut self,
-        inline_statements: &Vec<InlineStatement>
+        items: &[ListItem]
     ) -> Result<String, JiraiErr> {
         let mut lines: Vec<String> = Vec::new();
-        for i_statement in inline_statements{
-            lines.push(self.generate_inline_statement(&i_statement)?);
+        for item in items{
+            lines.push(self.generate_list_item_code(item)?);
         }
-        let joined: String;
-        if self.minify{
-            joined = lines.into_iter().collect::<String>();
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
         }
         else {
-            joined = lines.join("\n");
-        }
+            lines.join("\n")
+        };
         Ok(format!("<ul>{}</ul>", joined))
     }
 
+    /// The function to generate the HTML code
+    /// for an ordered list and nested elements 
+    /// and return it. The `start` attribute is
+    /// only emitted if the list does not start
+    /// at one. If the operation fails, an 
+    /// error is returned.
+    pub fn generate_ordered_list_code(
+        &mut self,
+        first: &usize,
+        items: &[ListItem]
+    ) -> Result<String, JiraiErr> {
+        let mut lines: Vec<String> = Vec::new();
+        for item in items{
+            lines.push(self.generate_list_item_code(item)?);
+        }
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
+        if *first == 1 {
+            Ok(format!("<ol>{}</ol>", joined))
+        }
+        else {
+            Ok(format!("<ol start=\"{}\">{}</ol>", first, joined))
+        }
+    }
+
     /// The function to generate the HTML code
     /// for an inline element and nested elements 
     /// and return it. If the operation fails, an 
@@ -207,30 +365,41 @@ impl HTMLCodeGenerator{
         inline_statement: &InlineStatement
     ) -> Result<String, JiraiErr> {
         match inline_statement{
-            InlineStatement::Text(text) => Ok(text.to_string()),
-            InlineStatement::Code(code) => Ok(self.generate_code_code(&code)),
-            InlineStatement::Link(link) => Ok(self.generate_link_code(&link)?),
-            InlineStatement::Image(image) => Ok(self.generate_image_code(&image)?),
-            InlineStatement::BoldText(nested) => Ok(self.generate_italic_code(&nested)?),
-            InlineStatement::ItalicText(nested) => Ok(self.generate_italic_code(&nested)?),
-            InlineStatement::ListItem(nested) => Ok(self.generate_list_item_code(&nested)?),
-            InlineStatement::BlockQuote(quote) => Ok(self.generate_block_quote_code(&quote))
+            InlineStatement::Text(_, text) => Ok(escape_html(text)),
+            InlineStatement::Code(_, code) => Ok(self.generate_code_code(code)),
+            InlineStatement::Comment(_, comment) => Ok(self.generate_comment_code(comment)),
+            InlineStatement::Sample(_, sample) => Ok(self.generate_sample_code(sample)),
+            InlineStatement::Keyboard(_, keys) => Ok(self.generate_keyboard_code(keys)),
+            InlineStatement::Subscript(_, text) => Ok(self.generate_subscript_code(text)),
+            InlineStatement::Superscript(_, text) => Ok(self.generate_superscript_code(text)),
+            InlineStatement::SoftBreak(_) => Ok(self.generate_soft_break_code()),
+            InlineStatement::Link(location, link) => Ok(self.generate_link_code(location, link)?),
+            InlineStatement::Image(location, image) => Ok(self.generate_image_code(location, image)?),
+            InlineStatement::BoldText(_, nested) => Ok(self.generate_bold_code(nested)?),
+            InlineStatement::ItalicText(_, nested) => Ok(self.generate_italic_code(nested)?),
+            InlineStatement::HighlightText(_, nested) => Ok(self.generate_highlight_code(nested)?),
+            InlineStatement::UnderlineText(_, nested) => Ok(self.generate_underline_code(nested)?),
+            InlineStatement::StrikethroughText(_, nested) => Ok(self.generate_strikethrough_code(nested)?),
+            InlineStatement::Quote(_, quote) => Ok(self.generate_quote_code(quote)),
+            InlineStatement::FootnoteReference(_, id) => Ok(self.generate_footnote_reference_code(id))
         }
     }
 
     /// The function to generate the HTML code
     /// for an inline image and return it. If the
-    /// operation fails, an error is returned.
+    /// operation fails, an error citing the image's
+    /// location is returned.
     pub fn generate_image_code(
         &mut self,
+        location: &Location,
         image: &Image
     ) -> Result<String, JiraiErr> {
         match &image.alt{
             Some(alt_text) => Ok(
                 format!(
                     "<img alt=\"{}\" src=\"{}\"/>", 
-                    alt_text, 
-                    image.url
+                    escape_html(alt_text), 
+                    escape_html(&image.url)
                 )
             ),
             None => {
@@ -238,8 +407,9 @@ impl HTMLCodeGenerator{
                     Err::<String, JiraiErr>(
                         JiraiErr::new(
                             &format!(
-                                "No \"alt\" text supplied to image \"{}\"!",
-                                &image.url
+                                "No \"alt\" text supplied to image \"{}\" at position \"{}\"!",
+                                &image.url,
+                                &location.start
                             )
                         )
                     )
@@ -248,7 +418,7 @@ impl HTMLCodeGenerator{
                     Ok(
                         format!(
                             "<img src=\"{}\"/>",
-                            image.url
+                            escape_html(&image.url)
                         )
                     )
                 }
@@ -258,18 +428,20 @@ impl HTMLCodeGenerator{
 
     /// The function to generate the HTML code
     /// for an inline link and return it. If the
-    /// operation fails, an error is returned.
+    /// operation fails, an error citing the link's
+    /// location is returned.
     pub fn generate_link_code(
         &mut self,
+        location: &Location,
         link: &Link
     ) -> Result<String, JiraiErr> {
         match &link.alt{
             Some(alt_text) => Ok(
                 format!(
                     "<a alt=\"{}\" href=\"{}\">{}</a>", 
-                    alt_text, 
-                    link.url,
-                    link.link_text
+                    escape_html(alt_text), 
+                    escape_html(&link.url),
+                    escape_html(&link.link_text)
                 )
             ),
             None => {
@@ -277,8 +449,9 @@ impl HTMLCodeGenerator{
                     Err::<String, JiraiErr>(
                         JiraiErr::new(
                             &format!(
-                                "No \"alt\" text supplied to link \"{}\"!",
-                                &link.url
+                                "No \"alt\" text supplied to link \"{}\" at position \"{}\"!",
+                                &link.url,
+                                &location.start
                             )
                         )
                     )
@@ -287,8 +460,8 @@ impl HTMLCodeGenerator{
                     Ok(
                         format!(
                             "<a href=\"{}\">{}</a>", 
-                            link.url,
-                            link.link_text
+                            escape_html(&link.url),
+                            escape_html(&link.link_text)
                         )
                     )
                 }
@@ -302,35 +475,424 @@ impl HTMLCodeGenerator{
         &mut self,
         code: &str
     ) -> String {
-        format!("<code>{}</code>", code)
+        format!("<code>{}</code>", escape_html(code))
     }
 
     /// The function to generate the HTML code
-    /// for block quote and return it. 
-    pub fn generate_block_quote_code(
+    /// for a table, its rows, and the inline
+    /// elements inside its cells and return it.
+    /// If the operation fails, an error is
+    /// returned.
+    pub fn generate_table_code(
+        &mut self,
+        alignments: &[Option<Alignment>],
+        header: &TableRow,
+        rows: &[TableRow]
+    ) -> Result<String, JiraiErr> {
+        let head: String = self.generate_table_row_code(alignments, header, "th")?;
+        let mut body: Vec<String> = Vec::new();
+        for row in rows {
+            body.push(self.generate_table_row_code(alignments, row, "td")?);
+        }
+        let lines: Vec<String> = vec![
+            "<table>".to_string(),
+            format!("<thead>{}</thead>", head),
+            format!("<tbody>{}</tbody>", body.concat()),
+            "</table>".to_string()
+        ];
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
+        Ok(joined)
+    }
+
+    /// The function to generate the HTML code
+    /// for a row of a table and the inline elements
+    /// inside its cells and return it. The cells
+    /// are wrapped in elements with the supplied
+    /// tag. If the operation fails, an error is
+    /// returned.
+    pub fn generate_table_row_code(
+        &mut self,
+        alignments: &[Option<Alignment>],
+        row: &TableRow,
+        tag: &str
+    ) -> Result<String, JiraiErr> {
+        let mut cells: Vec<String> = Vec::new();
+        for (idx, cell) in row.cells.iter().enumerate(){
+            let mut contents: Vec<String> = Vec::new();
+            for inline_statement in cell {
+                contents.push(self.generate_inline_statement(inline_statement)?);
+            }
+            let style: &str = match alignments.get(idx){
+                Some(Some(Alignment::Left)) => " style=\"text-align:left\"",
+                Some(Some(Alignment::Right)) => " style=\"text-align:right\"",
+                Some(Some(Alignment::Center)) => " style=\"text-align:center\"",
+                _ => ""
+            };
+            cells.push(format!("<{}{}>{}</{}>", tag, style, contents.concat(), tag));
+        }
+        Ok(format!("<tr>{}</tr>", cells.concat()))
+    }
+
+    /// The function to number the footnotes of
+    /// the AST in the order they are first referenced
+    /// in. Undefined footnotes, unused footnotes,
+    /// and footnotes defined more than once are
+    /// recorded as diagnostics. The references to
+    /// each footnote that will be generated, including
+    /// those inside the definitions of other footnotes,
+    /// are counted. The definitions of the numbered
+    /// footnotes are returned in the order of their
+    /// numbers.
+    pub fn number_footnotes(
+        &mut self
+    ) -> Vec<Statement> {
+        let references: Vec<(String, Location)> = collect_footnote_references(&self.statements);
+        let mut definitions: Vec<Statement> = Vec::new();
+        self.footnotes = Vec::new();
+        self.diagnostics = Vec::new();
+        let all_definitions: Vec<Statement> = collect_footnote_definitions(&self.statements);
+        for definition in &all_definitions {
+            if let Statement::FootnoteDefinition(location, id, _) = &definition {
+                let is_duplicate: bool = definitions.iter().any(|other|
+                    matches!(other, Statement::FootnoteDefinition(_, other_id, _) if other_id == id)
+                );
+                if is_duplicate {
+                    self.diagnose(location, &format!("Duplicate footnote \"{}\" defined", id));
+                }
+                else if !references.iter().any(|(reference, _)| reference == id){
+                    self.diagnose(location, &format!("Unused footnote \"{}\" defined", id));
+                }
+                else {
+                    definitions.push(definition.clone());
+                }
+            }
+        }
+        for (id, location) in &references {
+            let is_defined: bool = definitions.iter().any(|definition|
+                matches!(definition, Statement::FootnoteDefinition(_, defined, _) if defined == id)
+            );
+            if !is_defined {
+                self.diagnose(location, &format!("Undefined footnote \"{}\" referenced", id));
+            }
+            else if !self.footnotes.contains(id){
+                self.footnotes.push(id.clone());
+            }
+        }
+        self.footnote_references = vec![0; self.footnotes.len()];
+        let mut ordered: Vec<Statement> = Vec::new();
+        for id in &self.footnotes {
+            ordered.extend(definitions.iter().find(|definition|
+                matches!(definition, Statement::FootnoteDefinition(_, defined, _) if defined == id)
+            ).cloned());
+        }
+        let defined: Vec<(String, Location)> = collect_footnote_references(&all_definitions);
+        let body: Vec<(String, Location)> = references
+            .into_iter()
+            .filter(|reference| !defined.contains(reference))
+            .collect::<Vec<(String, Location)>>();
+        self.footnote_totals = vec![0; self.footnotes.len()];
+        for (id, _) in body.iter().chain(collect_footnote_references(&ordered).iter()){
+            if let Some(idx) = self.footnotes.iter().position(|footnote| footnote == id){
+                self.footnote_totals[idx] += 1;
+            }
+        }
+        ordered
+    }
+
+    /// The function to record a diagnostic
+    /// for the supplied location. The position
+    /// of the location is appended to the
+    /// supplied message. Nothing is returned.
+    pub fn diagnose(
+        &mut self,
+        location: &Location,
+        message: &str
+    ) {
+        self.diagnostics.push(
+            Diagnostic::new(
+                &location.span,
+                &location.end,
+                &location.start,
+                &format!("{} at position \"{}\"!", message, &location.start)
+            )
+        );
+    }
+
+    /// The function to generate the HTML code
+    /// for a footnote reference and return it.
+    /// The reference links to its footnote and
+    /// carries an identifier the footnote can
+    /// link back to. A reference to an undefined
+    /// footnote is emitted without a link.
+    pub fn generate_footnote_reference_code(
+        &mut self,
+        id: &str
+    ) -> String {
+        match self.footnotes.iter().position(|footnote| footnote == id){
+            Some(idx) => {
+                let number: usize = idx + 1;
+                self.footnote_references[idx] += 1;
+                format!(
+                    "<sup id=\"{}\"><a href=\"#fn-{}\" role=\"doc-noteref\">{}</a></sup>",
+                    footnote_reference_id(&number, &self.footnote_references[idx]),
+                    number,
+                    number
+                )
+            },
+            None => format!("<sup>[{}]</sup>", escape_html(id))
+        }
+    }
+
+    /// The function to generate the HTML code
+    /// for the section holding the supplied
+    /// footnote definitions and return it. Each
+    /// footnote links back to every reference
+    /// to it. If the operation fails, an error
+    /// is returned.
+    pub fn generate_footnotes_code(
+        &mut self,
+        definitions: &[Statement]
+    ) -> Result<String, JiraiErr> {
+        let mut lines: Vec<String> = Vec::new();
+        for (idx, definition) in definitions.iter().enumerate(){
+            let contents: &[InlineStatement] = match definition {
+                Statement::FootnoteDefinition(_, _, contents) => contents,
+                _ => continue
+            };
+            let number: usize = idx + 1;
+            let mut code: Vec<String> = Vec::new();
+            for inline_statement in contents {
+                code.push(self.generate_inline_statement(inline_statement)?);
+            }
+            for reference in 1..=self.footnote_totals[idx] {
+                code.push(
+                    format!(
+                        " <a href=\"#{}\" role=\"doc-backlink\">↩</a>",
+                        footnote_reference_id(&number, &reference)
+                    )
+                );
+            }
+            lines.push(format!("<li id=\"fn-{}\">{}</li>", number, code.concat()));
+        }
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
+        Ok(format!("<section class=\"footnotes\" role=\"doc-endnotes\"><ol>{}</ol></section>", joined))
+    }
+
+    /// The function to generate the HTML code
+    /// for a definition list, its terms, and their
+    /// definitions and return it. If the operation
+    /// fails, an error is returned.
+    pub fn generate_definition_list_code(
+        &mut self,
+        items: &[DefinitionItem]
+    ) -> Result<String, JiraiErr> {
+        let mut lines: Vec<String> = Vec::new();
+        for item in items {
+            let mut term: Vec<String> = Vec::new();
+            for inline_statement in &item.term {
+                term.push(self.generate_inline_statement(inline_statement)?);
+            }
+            lines.push(format!("<dt>{}</dt>", term.concat()));
+            for definition in &item.definitions {
+                let mut code: Vec<String> = Vec::new();
+                for inline_statement in definition {
+                    code.push(self.generate_inline_statement(inline_statement)?);
+                }
+                lines.push(format!("<dd>{}</dd>", code.concat()));
+            }
+        }
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
+        Ok(format!("<dl>{}</dl>", joined))
+    }
+
+    /// The function to generate the HTML code
+    /// for a divider and return it.
+    pub fn generate_divider_code(
+        &mut self
+    ) -> String {
+        match &self.divider_decoration {
+            Some(decoration) => format!(
+                "<div class=\"jirai-divider\" role=\"separator\">{}</div>",
+                escape_html(decoration)
+            ),
+            None => "<hr/>".to_string()
+        }
+    }
+
+    /// The function to generate the HTML code
+    /// for a code block and return it. The first
+    /// word of the info string names the language
+    /// of the code. The code is escaped, so it
+    /// shows up exactly as it was written.
+    pub fn generate_code_block_code(
+        &mut self,
+        info: &Option<String>,
+        code: &str
+    ) -> String {
+        match info.as_deref().and_then(|info| info.split_whitespace().next()){
+            Some(language) => format!(
+                "<pre><code class=\"language-{}\">{}</code></pre>",
+                escape_html(language),
+                escape_html(code)
+            ),
+            None => format!("<pre><code>{}</code></pre>", escape_html(code))
+        }
+    }
+
+    /// The function to generate the HTML code
+    /// for superscript text and return it.
+    pub fn generate_superscript_code(
+        &mut self,
+        text: &str
+    ) -> String {
+        format!("<sup>{}</sup>", escape_html(text))
+    }
+
+    /// The function to generate the HTML code
+    /// for subscript text and return it.
+    pub fn generate_subscript_code(
+        &mut self,
+        text: &str
+    ) -> String {
+        format!("<sub>{}</sub>", escape_html(text))
+    }
+
+    /// The function to generate the HTML code
+    /// for keyboard input and return it. A key
+    /// combination is emitted as a `kbd` element
+    /// holding a nested `kbd` element for each key.
+    pub fn generate_keyboard_code(
+        &mut self,
+        keys: &[String]
+    ) -> String {
+        match keys {
+            [key] => format!("<kbd>{}</kbd>", escape_html(key)),
+            _ => format!(
+                "<kbd>{}</kbd>",
+                keys
+                    .iter()
+                    .map(|key| format!("<kbd>{}</kbd>", escape_html(key)))
+                    .collect::<Vec<String>>()
+                    .join("+")
+            )
+        }
+    }
+
+    /// The function to generate the HTML code
+    /// for sample output and return it.
+    pub fn generate_sample_code(
+        &mut self,
+        sample: &str
+    ) -> String {
+        format!("<samp>{}</samp>", escape_html(sample))
+    }
+
+    /// The function to generate the HTML code
+    /// for a comment and return it. If comments
+    /// are not emitted, an empty string is returned.
+    /// Double hyphens are broken up so that the
+    /// comment text cannot end the HTML comment.
+    pub fn generate_comment_code(
+        &mut self,
+        comment: &str
+    ) -> String {
+        if self.emit_comments {
+            let mut sanitized: String = comment.to_string();
+            while sanitized.contains("--"){
+                sanitized = sanitized.replace("--", "- -");
+            }
+            format!("<!--{}-->", sanitized)
+        }
+        else {
+            String::new()
+        }
+    }
+
+    /// The function to generate the HTML code
+    /// for a soft break between two lines of
+    /// a paragraph and return it. The line break
+    /// is replaced by a space. Paragraphs and list
+    /// items that are not minified already put each
+    /// inline element on a line of its own and skip
+    /// soft breaks.
+    pub fn generate_soft_break_code(
+        &mut self
+    ) -> String {
+        " ".to_string()
+    }
+
+    /// The function to generate the HTML code
+    /// for an inline quote and return it. 
+    pub fn generate_quote_code(
         &mut self,
         quote: &str
     ) -> String {
-        format!("<blockquote>{}</blockquote>", quote)
+        format!("<q>{}</q>", escape_html(quote))
+    }
+
+    /// The function to generate the HTML code
+    /// for a block quote, the block elements inside
+    /// it and its attribution and return it. If the
+    /// operation fails, an error is returned.
+    pub fn generate_block_quote_code(
+        &mut self,
+        children: &[Statement],
+        attribution: &Option<Vec<InlineStatement>>
+    ) -> Result<String, JiraiErr> {
+        let mut lines: Vec<String> = Vec::new();
+        for child in children {
+            let code: String = self.generate_statement(child)?;
+            if !code.is_empty(){
+                lines.push(code);
+            }
+        }
+        if let Some(attribution) = attribution {
+            let mut cite: Vec<String> = Vec::new();
+            for inline_statement in attribution {
+                cite.push(self.generate_inline_statement(inline_statement)?);
+            }
+            lines.push(format!("<cite>{}</cite>", cite.concat()));
+        }
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
+        Ok(format!("<blockquote>{}</blockquote>", joined))
     }
 
     /// The function to generate the HTML code
     /// for italic text and return it. 
     pub fn generate_italic_code(
         &mut self,
-        inline_statements: &Vec<InlineStatement>
+        inline_statements: &[InlineStatement]
     ) -> Result<String, JiraiErr> {
         let mut lines: Vec<String> = Vec::new();
         for inline_statement in inline_statements {
-            lines.push(self.generate_inline_statement(&inline_statement)?);
+            lines.push(self.generate_inline_statement(inline_statement)?);
         }
-        let joined: String;
-        if self.minify{
-            joined = lines.into_iter().collect::<String>();
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
         }
         else {
-            joined = lines.join("\n");
-        }
+            lines.join("\n")
+        };
         Ok(format!("<i>{}</i>", joined))
     }
 
@@ -338,39 +900,144 @@ impl HTMLCodeGenerator{
     /// for bold text and return it. 
     pub fn generate_bold_code(
         &mut self,
-        inline_statements: &Vec<InlineStatement>
+        inline_statements: &[InlineStatement]
     ) -> Result<String, JiraiErr> {
         let mut lines: Vec<String> = Vec::new();
         for inline_statement in inline_statements {
-            lines.push(self.generate_inline_statement(&inline_statement)?);
+            lines.push(self.generate_inline_statement(inline_statement)?);
         }
-        let joined: String;
-        if self.minify{
-            joined = lines.into_iter().collect::<String>();
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
         }
         else {
-            joined = lines.join("\n");
-        }
+            lines.join("\n")
+        };
         Ok(format!("<b>{}</b>", joined))
     }
 
     /// The function to generate the HTML code
-    /// for a list item and return it. 
-    pub fn generate_list_item_code(
+    /// for struck-through text and return it. 
+    pub fn generate_strikethrough_code(
         &mut self,
-        inline_statements: &Vec<InlineStatement>
+        inline_statements: &[InlineStatement]
     ) -> Result<String, JiraiErr> {
         let mut lines: Vec<String> = Vec::new();
         for inline_statement in inline_statements {
-            lines.push(self.generate_inline_statement(&inline_statement)?);
+            lines.push(self.generate_inline_statement(inline_statement)?);
         }
-        let joined: String;
-        if self.minify{
-            joined = lines.into_iter().collect::<String>();
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
+        Ok(format!("<del>{}</del>", joined))
+    }
+
+    /// The function to generate the HTML code
+    /// for underlined text and return it. 
+    pub fn generate_underline_code(
+        &mut self,
+        inline_statements: &[InlineStatement]
+    ) -> Result<String, JiraiErr> {
+        let mut lines: Vec<String> = Vec::new();
+        for inline_statement in inline_statements {
+            lines.push(self.generate_inline_statement(inline_statement)?);
+        }
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
+        Ok(format!("<u>{}</u>", joined))
+    }
+
+    /// The function to generate the HTML code
+    /// for highlighted text and return it. 
+    pub fn generate_highlight_code(
+        &mut self,
+        inline_statements: &[InlineStatement]
+    ) -> Result<String, JiraiErr> {
+        let mut lines: Vec<String> = Vec::new();
+        for inline_statement in inline_statements {
+            lines.push(self.generate_inline_statement(inline_statement)?);
+        }
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
         }
         else {
-            joined = lines.join("\n");
+            lines.join("\n")
+        };
+        Ok(format!("<mark>{}</mark>", joined))
+    }
+
+    /// The function to generate the HTML code
+    /// for a list item and the lists nested
+    /// inside it and return it. Task items
+    /// start with a disabled checkbox.
+    pub fn generate_list_item_code(
+        &mut self,
+        item: &ListItem
+    ) -> Result<String, JiraiErr> {
+        let mut lines: Vec<String> = Vec::new();
+        match item.task {
+            Some(true) => lines.push("<input type=\"checkbox\" checked disabled/>".to_string()),
+            Some(false) => lines.push("<input type=\"checkbox\" disabled/>".to_string()),
+            None => {}
+        };
+        for inline_statement in &item.contents {
+            if !self.minify && matches!(inline_statement, InlineStatement::SoftBreak(_)){
+                continue;
+            }
+            lines.push(self.generate_inline_statement(inline_statement)?);
         }
+        for child in &item.children {
+            lines.push(self.generate_statement(child)?);
+        }
+        let joined: String = if self.minify{
+            lines.into_iter().collect::<String>()
+        }
+        else {
+            lines.join("\n")
+        };
         Ok(format!("<li>{}</li>", joined))
     }
 }
+
+/// A function to escape the characters
+/// of the supplied text that have a special
+/// meaning in HTML and return the escaped
+/// text.
+pub fn escape_html(
+    sub: &str
+) -> String {
+    let mut result: String = String::new();
+    for c in sub.chars(){
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            _ => result.push(c)
+        };
+    }
+    result
+}
+
+/// A function to return the identifier
+/// of the supplied reference to the footnote
+/// with the supplied number. The first reference
+/// to a footnote is identified by the number
+/// alone.
+pub fn footnote_reference_id(
+    number: &usize,
+    reference: &usize
+) -> String {
+    if *reference > 1 {
+        format!("fnref-{}-{}", number, reference)
+    }
+    else {
+        format!("fnref-{}", number)
+    }
+}
@@ -0,0 +1,247 @@
+/*
+Jirai by Alyx Shang.
+Licensed under the FSL v1.
+*/
+
+/// Importing the structure
+/// for ranges of indices.
+use std::ops::Range;
+
+/// Importing the structure
+/// holding information on
+/// a capture token.
+use super::lexer::Token;
+
+/// Importing the data structure
+/// to encapsulate data about the
+/// byte offsets of a token.
+use super::lexer::Span;
+
+/// Importing the structure
+/// to lazily tokenize Jirai
+/// source code.
+use super::stream::Lexer;
+
+/// Importing the structure
+/// to catch and handle errors.
+use super::err::JiraiErr;
+
+/// Importing the enumeration
+/// containing all possible types
+/// Jirai tokens.
+use super::lexer::TokenType;
+
+/// Importing the structure to
+/// keep track of the lexer's place
+/// inside Jirai source code.
+use super::lexer::LexCursor;
+
+/// Importing the function to
+/// tokenize a string of Jirai
+/// source code.
+use super::lexer::tokenize_string;
+
+/// Importing the function to find
+/// the start of the line containing
+/// a byte offset.
+use super::lexer::line_start_offset;
+
+/// A structure to encapsulate
+/// an edit of Jirai source code.
+/// The bytes covered by the `span`
+/// field are replaced with the
+/// contents of the `text` field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextEdit{
+    pub span: Span,
+    pub text: String
+}
+
+/// Implementing functions
+/// for the `TextEdit` structure.
+impl TextEdit {
+
+    /// A function to create a new
+    /// instance of the `TextEdit`
+    /// structure and return it.
+    pub fn new(
+        span: &Span,
+        text: &str
+    ) -> TextEdit {
+        TextEdit{
+            span: span.clone(),
+            text: text.to_string()
+        }
+    }
+}
+
+/// A structure to hold a string
+/// of Jirai source code together
+/// with the tokens lexed from it,
+/// so that edits of the source only
+/// have to re-tokenize the lines
+/// they touch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LexedDocument{
+    pub source: String,
+    pub tokens: Vec<Token>
+}
+
+/// Implementing functions
+/// for the `LexedDocument`
+/// structure.
+impl LexedDocument {
+
+    /// A function to tokenize the supplied
+    /// Jirai source code and create a new
+    /// instance of the `LexedDocument` structure
+    /// from it. If the source cannot be tokenized,
+    /// an error is returned.
+    pub fn new(
+        source: &str
+    ) -> Result<LexedDocument, JiraiErr> {
+        let tokens: Vec<Token> = tokenize_string(source)?;
+        Ok(
+            LexedDocument{
+                source: source.to_string(),
+                tokens
+            }
+        )
+    }
+
+    /// A function to apply an edit to the
+    /// source code and re-tokenize only the
+    /// lines affected by it. Lexing restarts
+    /// at the start of the first edited line,
+    /// or of the line before it if that line
+    /// ends in a carriage return a line feed
+    /// could be joined to. If that line lies
+    /// inside a token spanning several lines, like
+    /// a code block or a comment, lexing restarts at
+    /// the start of the line that token starts on, so
+    /// that the lexer's state for that line, like
+    /// open table rows or text styles, is rebuilt. Lexing
+    /// stops as soon as a line starts at
+    /// the same place as a line of the old
+    /// tokens. All tokens after that point are
+    /// shifted by the size of the edit. If the
+    /// operation is successful, the range of
+    /// indices of the re-tokenized tokens is
+    /// returned. If the edit does not cover a
+    /// valid range of the source or the edited
+    /// source cannot be tokenized, an error is
+    /// returned and the document is left as
+    /// it was.
+    pub fn apply_edit(
+        &mut self,
+        edit: &TextEdit
+    ) -> Result<Range<usize>, JiraiErr> {
+        let removed: &str = edit.span.slice(&self.source)?;
+        let mut source: String = String::new();
+        source.push_str(&self.source[..edit.span.start]);
+        source.push_str(&edit.text);
+        source.push_str(&self.source[edit.span.end..]);
+        let delta: isize = edit.text.len() as isize - removed.len() as isize;
+        let edited_end: usize = edit.span.start + edit.text.len();
+        let mut line_start: usize = line_start_offset(&self.source, &edit.span.start)?;
+        if self.source[..line_start].ends_with('\r'){
+            line_start = line_start_offset(&self.source, &(line_start - 1))?;
+        }
+        let mut first: usize = self.tokens
+            .iter()
+            .position(|token| token.span.end > line_start)
+            .unwrap_or(self.tokens.len());
+        while first > 0 && self.tokens[first - 1].token_type != TokenType::NewLine {
+            first -= 1;
+        }
+        let restart: LexCursor = match self.tokens.get(first){
+            Some(token) => LexCursor::new(
+                &token.start.line,
+                &token.start.column,
+                &token.span.start
+            ),
+            None => cursor_after(first.checked_sub(1).and_then(|idx| self.tokens.get(idx)))
+        };
+        let lexer: Lexer<&[u8]> = Lexer::with_cursor(
+            &source.as_bytes()[restart.offset..],
+            &restart
+        );
+        let mut relexed: Vec<Token> = Vec::new();
+        let mut resync: Option<(usize, Token)> = None;
+        let mut at_line_start: bool = true;
+        for lexed in lexer {
+            let token: Token = lexed?;
+            if at_line_start && token.span.start >= edited_end {
+                let old_offset: usize = (token.span.start as isize - delta) as usize;
+                let old_idx: Option<usize> = self.tokens
+                    .binary_search_by_key(&old_offset, |old| old.span.start)
+                    .ok()
+                    .filter(|idx| *idx > first && self.tokens[idx - 1].token_type == TokenType::NewLine);
+                if let Some(old_idx) = old_idx {
+                    resync = Some((old_idx, token));
+                    break;
+                }
+            }
+            at_line_start = token.token_type == TokenType::NewLine;
+            relexed.push(token);
+        }
+        let (last, tail): (usize, Vec<Token>) = match resync {
+            Some((old_idx, token)) => {
+                let old: &Token = &self.tokens[old_idx];
+                let lines: isize = token.start.line as isize - old.start.line as isize;
+                let columns: isize = token.start.column as isize - old.start.column as isize;
+                let tail: Vec<Token> = self.tokens[old_idx..]
+                    .iter()
+                    .map(|old| shift_token(old, &delta, &lines, &columns))
+                    .collect::<Vec<Token>>();
+                (old_idx, tail)
+            },
+            None => (self.tokens.len(), Vec::new())
+        };
+        let changed: Range<usize> = first..first + relexed.len();
+        self.tokens.truncate(last);
+        self.tokens.splice(first.., relexed.into_iter().chain(tail));
+        self.source = source;
+        Ok(changed)
+    }
+}
+
+/// A function to return a cursor
+/// pointing just after the supplied
+/// token. If no token is supplied, a
+/// cursor pointing at the start of
+/// the source is returned.
+pub fn cursor_after(
+    token: Option<&Token>
+) -> LexCursor {
+    match token {
+        Some(token) => {
+            let line: usize = match token.token_type {
+                TokenType::NewLine => token.start.line + 1,
+                _ => token.end.line
+            };
+            LexCursor::new(&line, &token.end.column, &token.span.end)
+        },
+        None => LexCursor::new(&0, &0, &0)
+    }
+}
+
+/// A function to shift a token's byte
+/// offsets, lines, and columns by the
+/// supplied amounts and return the
+/// shifted token.
+pub fn shift_token(
+    token: &Token,
+    bytes: &isize,
+    lines: &isize,
+    columns: &isize
+) -> Token {
+    let mut shifted: Token = token.clone();
+    shifted.span.start = (shifted.span.start as isize + bytes) as usize;
+    shifted.span.end = (shifted.span.end as isize + bytes) as usize;
+    shifted.start.line = (shifted.start.line as isize + lines) as usize;
+    shifted.end.line = (shifted.end.line as isize + lines) as usize;
+    shifted.start.column = (shifted.start.column as isize + columns) as usize;
+    shifted.end.column = (shifted.end.column as isize + columns) as usize;
+    shifted
+}
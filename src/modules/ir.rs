@@ -5,10 +5,12 @@ Licensed under the FSL v1.
 
 /// Importing the structure
 /// to catch and handle errors.
+#[allow(unused_imports)]
 use super::err::JiraiErr;
 
 /// Importing the enumeration
 /// describing all possible types
 /// of block-level statements
 /// Jirai source code can contain.
+#[allow(unused_imports)]
 use super::parser::Statement;
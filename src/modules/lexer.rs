@@ -3,19 +3,45 @@ Jirai by Alyx Shang.
 Licensed under the FSL v1.
 */
 
+/// Importing the standard
+/// "Display" trait.
+use std::fmt::Display;
+
+/// Importing the standard
+/// "Formatter" structure.
+use std::fmt::Formatter;
+
 /// Importing the structure
 /// to catch and handle errors.
 use super::err::JiraiErr;
 
+/// Importing the structure
+/// describing a problem found
+/// in Jirai source code.
+use super::err::Diagnostic;
+
+/// Importing the standard
+/// "Result" enum for formatting.
+use std::fmt::Result as FmtResult;
+
 /// An enumeration that
 /// lists all possible types
 /// of Jirai tokens.
 #[derive(Clone, Debug, PartialEq)]
 pub enum TokenType{
+    Error,
+    Indent,
+    Comment,
     NewLine,
+    Divider,
     BoldText,
     OpenCurly,
     OpenAngle,
+    OpenQuote,
+    OpenFence,
+    RawString,
+    CloseQuote,
+    CloseFence,
     UserString,
     CloseAngle,
     CloseCurly,
@@ -28,7 +54,12 @@ pub enum TokenType{
     OpenBracket,
     CloseBracket,
     HeadingMarker,
+    HighlightText,
+    UnderlineText,
+    TableDelimiter,
     DocumentLimiter,
+    OrderedListMarker,
+    StrikethroughText,
 }
 
 /// A structure encapsulating
@@ -36,6 +67,7 @@ pub enum TokenType{
 /// in Jirai source code.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Token{
+    pub span: Span,
     pub end: Position,
     pub start: Position,
     pub token_type: TokenType,
@@ -51,12 +83,14 @@ impl Token {
     /// of the `Token` structure
     /// and return that instance.
     pub fn new(
+        span: &Span,
         end: &Position,
         start: &Position,
         token_type: &TokenType,
         value: &Option<String>
     ) -> Token {
         Token{
+            span: span.clone(),
             end: end.clone(),
             start: start.clone(),
             token_type: token_type.clone(),
@@ -66,9 +100,84 @@ impl Token {
 
 }
 
+/// A structure encapsulating
+/// data about a token encountered
+/// in Jirai source code whose value
+/// is a slice of that source code.
+/// The value holds the raw text, so
+/// escape sequences are only resolved
+/// when converting into a `Token`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BorrowedToken<'a>{
+    pub span: Span,
+    pub end: Position,
+    pub start: Position,
+    pub token_type: TokenType,
+    pub value: Option<&'a str>
+}
+
+/// Implementing functions for
+/// the `BorrowedToken` structure.
+impl<'a> BorrowedToken<'a> {
+
+    /// Implementing a function
+    /// to create a new instance
+    /// of the `BorrowedToken` structure
+    /// and return that instance.
+    pub fn new(
+        span: &Span,
+        end: &Position,
+        start: &Position,
+        token_type: &TokenType,
+        value: &Option<&'a str>
+    ) -> BorrowedToken<'a> {
+        BorrowedToken{
+            span: span.clone(),
+            end: end.clone(),
+            start: start.clone(),
+            token_type: token_type.clone(),
+            value: *value
+        }
+    }
+
+    /// Implementing a function to
+    /// copy the borrowed token into
+    /// an owned `Token` and return it.
+    pub fn to_token(
+        &self
+    ) -> Token {
+        Token::from(self.clone())
+    }
+}
+
+/// Implementing the conversion
+/// from a borrowed token into
+/// an owned token.
+impl<'a> From<BorrowedToken<'a>> for Token {
+    fn from(
+        token: BorrowedToken<'a>
+    ) -> Token {
+        let value: Option<String> = match token.token_type {
+            TokenType::UserString => token.value.map(unescape),
+            _ => token.value.map(|value| value.to_string())
+        };
+        Token{
+            span: token.span,
+            end: token.end,
+            start: token.start,
+            token_type: token.token_type,
+            value
+        }
+    }
+}
+
 /// A structure to encapsulate
 /// data about the start and end
-/// of a captured token.
+/// of a captured token. The `line`
+/// field counts lines from zero. The
+/// `column` field counts characters
+/// from the start of the source, not
+/// from the start of the line.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Position{
     pub line: usize,
@@ -93,352 +202,1224 @@ impl Position {
         }
     }
 
-    /// A function to return a string
-    /// representation of the `Position`
-    /// structure.
-    pub fn to_string(
+}
+
+/// Implements the Display trait
+/// for the `Position` structure to
+/// obtain a string representation
+/// of it.
+impl Display for Position {
+    fn fmt(
+        &self, 
+        f: &mut Formatter
+    ) -> FmtResult {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// A structure to encapsulate
+/// the byte offsets of the start
+/// and end of a captured token in
+/// the original source string. The
+/// end offset is exclusive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span{
+    pub end: usize,
+    pub start: usize
+}
+
+/// Implementing important
+/// functions for the `Span`
+/// structure.
+impl Span {
+
+    /// A function to create a
+    /// new instance of the `Span`
+    /// structure and return it.
+    pub fn new(
+        end: &usize,
+        start: &usize
+    ) -> Span {
+        Span{
+            end: *end,
+            start: *start
+        }
+    }
+
+    /// A function to return the
+    /// number of bytes covered by
+    /// the `Span` structure.
+    pub fn len(
+        &self
+    ) -> usize {
+        self.end - self.start
+    }
+
+    /// A function to check whether
+    /// the `Span` structure covers
+    /// no bytes at all.
+    pub fn is_empty(
         &self
-    ) -> String {
-        format!(
-            "{:?}:{:?}", 
-            &self.line, 
-            &self.column
+    ) -> bool {
+        self.end == self.start
+    }
+
+    /// A function to retrieve the slice
+    /// of the supplied source covered by
+    /// the `Span` structure. If the span
+    /// does not lie on character boundaries
+    /// inside the source, an error is returned.
+    pub fn slice<'a>(
+        &self,
+        source: &'a str
+    ) -> Result<&'a str, JiraiErr> {
+        match source.get(self.start..self.end){
+            Some(slice) => Ok(slice),
+            None => Err::<&'a str, JiraiErr>(
+                JiraiErr::new(
+                    &format!(
+                        "Span \"{}..{}\" is not a valid range of the source!",
+                        &self.start,
+                        &self.end
+                    )
+                )
+            )
+        }
+    }
+}
+
+/// A function to check whether the
+/// supplied byte offset lies on a
+/// character boundary inside the source.
+/// If it does not, an error is returned.
+pub fn check_char_boundary(
+    source: &str,
+    offset: &usize
+) -> Result<(), JiraiErr> {
+    if !source.is_char_boundary(*offset){
+        return Err::<(), JiraiErr>(
+            JiraiErr::new(
+                &format!(
+                    "Byte offset \"{}\" is not a character boundary!",
+                    offset
+                )
+            )
+        );
+    }
+    Ok(())
+}
+
+/// A function to find the byte offset
+/// at which the line containing the
+/// supplied byte offset starts. If the
+/// offset does not lie on a character
+/// boundary inside the source, an error
+/// is returned.
+pub fn line_start_offset(
+    source: &str,
+    offset: &usize
+) -> Result<usize, JiraiErr> {
+    check_char_boundary(source, offset)?;
+    let start: usize = source[..*offset]
+        .rfind(['\n', '\r'])
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+    Ok(start)
+}
+
+/// A function to find the byte offset
+/// at which the supplied line starts.
+/// Lines are counted from zero and a
+/// carriage return followed by a line
+/// feed counts as a single line break.
+/// If the source has fewer lines, an
+/// error is returned.
+pub fn line_offset(
+    source: &str,
+    line: &usize
+) -> Result<usize, JiraiErr> {
+    let mut current: usize = 0;
+    let mut chars = source.char_indices().peekable();
+    if *line == 0 {
+        return Ok(0);
+    }
+    while let Some((idx, c)) = chars.next(){
+        if c == '\r' && chars.peek().map(|(_, next)| *next) == Some('\n'){
+            continue;
+        }
+        if c == '\n' || c == '\r' {
+            current += 1;
+            if current == *line {
+                return Ok(idx + 1);
+            }
+        }
+    }
+    Err::<usize, JiraiErr>(
+        JiraiErr::new(&format!("Line \"{}\" does not exist!", line))
+    )
+}
+
+/// A function to convert a byte offset
+/// into a column counted in characters.
+/// Like the `column` field of the `Position`
+/// structure, the column counts the characters
+/// from the start of the source, not from the
+/// start of the line. If the offset does not
+/// lie on a character boundary, an error is
+/// returned.
+pub fn byte_offset_to_char_column(
+    source: &str,
+    offset: &usize
+) -> Result<usize, JiraiErr> {
+    check_char_boundary(source, offset)?;
+    Ok(source[..*offset].chars().count())
+}
+
+/// A function to convert a byte offset
+/// into a column counted in UTF-16 code
+/// units from the start of the source. If
+/// the offset does not lie on a character
+/// boundary, an error is returned.
+pub fn byte_offset_to_utf16_column(
+    source: &str,
+    offset: &usize
+) -> Result<usize, JiraiErr> {
+    check_char_boundary(source, offset)?;
+    Ok(source[..*offset].encode_utf16().count())
+}
+
+/// A function to convert a byte offset
+/// into a column counted in characters
+/// from the start of its line. If the
+/// offset does not lie on a character
+/// boundary, an error is returned.
+pub fn byte_offset_to_line_char_column(
+    source: &str,
+    offset: &usize
+) -> Result<usize, JiraiErr> {
+    let start: usize = line_start_offset(source, offset)?;
+    Ok(source[start..*offset].chars().count())
+}
+
+/// A function to convert a byte offset
+/// into a column counted in UTF-16 code
+/// units from the start of its line, as
+/// the Language Server Protocol expects
+/// it. If the offset does not lie on a
+/// character boundary, an error is returned.
+pub fn byte_offset_to_line_utf16_column(
+    source: &str,
+    offset: &usize
+) -> Result<usize, JiraiErr> {
+    let start: usize = line_start_offset(source, offset)?;
+    Ok(source[start..*offset].encode_utf16().count())
+}
+
+/// A function to walk the line starting
+/// at the supplied byte offset until the
+/// supplied column, counted in the units
+/// the supplied function measures characters
+/// in, is reached. If the column lies on the
+/// line, its byte offset is returned. If it
+/// does not, `None` is returned.
+pub fn walk_line_column(
+    source: &str,
+    start: &usize,
+    column: &usize,
+    width: fn(&char) -> usize
+) -> Option<usize> {
+    let mut consumed: usize = 0;
+    for (idx, c) in source[*start..].char_indices(){
+        if consumed == *column {
+            return Some(start + idx);
+        }
+        if consumed > *column || c == '\n' || c == '\r' {
+            return None;
+        }
+        consumed += width(&c);
+    }
+    if consumed == *column {
+        return Some(source.len());
+    }
+    None
+}
+
+/// A function to convert a line and
+/// a column counted in characters into
+/// a byte offset. Like the `column` field
+/// of the `Position` structure, the column
+/// counts the characters from the start of
+/// the source, so the position of a token can
+/// be passed in as it is. If the column does
+/// not lie on the line, an error is returned.
+pub fn char_column_to_byte_offset(
+    source: &str,
+    line: &usize,
+    column: &usize
+) -> Result<usize, JiraiErr> {
+    let start: usize = line_offset(source, line)?;
+    let offset: Option<usize> = column
+        .checked_sub(source[..start].chars().count())
+        .and_then(|column| walk_line_column(source, &start, &column, |_| 1));
+    match offset {
+        Some(offset) => Ok(offset),
+        None => Err::<usize, JiraiErr>(
+            JiraiErr::new(
+                &format!(
+                    "Column \"{}\" does not exist on line \"{}\"!",
+                    column,
+                    line
+                )
+            )
+        )
+    }
+}
+
+/// A function to convert a line and
+/// a column counted in UTF-16 code units
+/// from the start of the source into a
+/// byte offset. If the column does not lie
+/// on the line or lies inside a character,
+/// an error is returned.
+pub fn utf16_column_to_byte_offset(
+    source: &str,
+    line: &usize,
+    column: &usize
+) -> Result<usize, JiraiErr> {
+    let start: usize = line_offset(source, line)?;
+    let offset: Option<usize> = column
+        .checked_sub(source[..start].encode_utf16().count())
+        .and_then(|column| walk_line_column(source, &start, &column, |c| c.len_utf16()));
+    match offset {
+        Some(offset) => Ok(offset),
+        None => Err::<usize, JiraiErr>(
+            JiraiErr::new(
+                &format!(
+                    "UTF-16 column \"{}\" does not exist on line \"{}\"!",
+                    column,
+                    line
+                )
+            )
+        )
+    }
+}
+
+/// A function to convert a line and
+/// a column counted in characters from
+/// the start of that line into a byte
+/// offset. If the column does not lie
+/// on the line, an error is returned.
+pub fn line_char_column_to_byte_offset(
+    source: &str,
+    line: &usize,
+    column: &usize
+) -> Result<usize, JiraiErr> {
+    let start: usize = line_offset(source, line)?;
+    match walk_line_column(source, &start, column, |_| 1){
+        Some(offset) => Ok(offset),
+        None => Err::<usize, JiraiErr>(
+            JiraiErr::new(
+                &format!(
+                    "Column \"{}\" does not exist on line \"{}\"!",
+                    column,
+                    line
+                )
+            )
+        )
+    }
+}
+
+/// A function to convert a line and a
+/// column counted in UTF-16 code units from
+/// the start of that line, as the Language
+/// Server Protocol sends it, into a byte
+/// offset. If the column does not lie on
+/// the line or lies inside a character, an
+/// error is returned.
+pub fn line_utf16_column_to_byte_offset(
+    source: &str,
+    line: &usize,
+    column: &usize
+) -> Result<usize, JiraiErr> {
+    let start: usize = line_offset(source, line)?;
+    match walk_line_column(source, &start, column, |c| c.len_utf16()){
+        Some(offset) => Ok(offset),
+        None => Err::<usize, JiraiErr>(
+            JiraiErr::new(
+                &format!(
+                    "UTF-16 column \"{}\" does not exist on line \"{}\"!",
+                    column,
+                    line
+                )
+            )
         )
     }
 }
 
 /// A function to check whether the
 /// passed character is any of the
-/// reserved characters. If it is,
-/// a boolean `false` is returned.
-/// If it is not, a boolean `true`
-/// is returned.
+/// reserved characters or an illegal
+/// control character. If it is, a boolean
+/// `false` is returned. If it is not, a
+/// boolean `true` is returned. The characters
+/// `-` and `^` are only meaningful as part
+/// of the `(^-^)` symbol and therefore
+/// count as text on their own.
 pub fn is_text(
     sub: &char
 ) -> bool {
-    if "<>*$()[]{}^-~#@\n\r"
-        .to_string()
-        .chars()
-        .collect::<Vec<char>>()
-        .contains(sub)
-    {
-        false
+    !"<>*$()[]{}~#@\n\r".contains(*sub) && !is_illegal(sub)
+}
+
+/// A function to check whether the
+/// passed character is a control character
+/// that may not appear in Jirai source
+/// code. Tabs and line breaks are allowed.
+/// A boolean reflecting this is returned.
+pub fn is_illegal(
+    sub: &char
+) -> bool {
+    sub.is_control() && !"\t\n\r".contains(*sub)
+}
+
+/// A function to check whether the
+/// passed character may follow a
+/// backslash to be read as literal
+/// text. A boolean reflecting this
+/// is returned.
+pub fn is_escapable(
+    sub: &char
+) -> bool {
+    "\\<>*$()[]{}^-~#@|_=".contains(*sub)
+}
+
+/// A function to map the two-character
+/// delimiter of inline text styles at the
+/// start of the supplied string onto the type
+/// of token it stands for. The delimiters are
+/// `~~` for strikethrough, `__` for underlined,
+/// and `==` for highlighted text. If the string
+/// does not start with one of them, `None`
+/// is returned.
+pub fn style_token_type(
+    sub: &str
+) -> Option<TokenType> {
+    if sub.starts_with("~~"){
+        Some(TokenType::StrikethroughText)
+    }
+    else if sub.starts_with("__"){
+        Some(TokenType::UnderlineText)
+    }
+    else if sub.starts_with("=="){
+        Some(TokenType::HighlightText)
     }
     else {
-        true
+        None
     }
 }
 
-/// A function to split a string
-/// of Jirai source into a vector
-/// of instances of the `Token` structure.
-/// If the tokenization of a Jirai source
-/// code string is successful, this vector
-/// is returned. If an illegal character 
-/// is detected, an error is returned.
-pub fn tokenize_string(
+/// A function to find the delimiter closing
+/// the inline text style opened by the delimiter
+/// at the start of the supplied string. Only
+/// the rest of the line is searched and only a
+/// delimiter following a character that is not
+/// whitespace can close the style. If one is
+/// found, its byte offset is returned. If no
+/// closing delimiter is found, `None` is returned.
+pub fn closing_style_offset(
     sub: &str
-) -> Result<Vec<Token>, JiraiErr> {
-    let chars: Vec<char> = sub
-        .to_string()
+) -> Option<usize> {
+    let delimiter: &str = sub.get(..2)?;
+    let mut previous: char = ' ';
+    let mut chars = sub.char_indices().skip(2);
+    while let Some((idx, c)) = chars.next(){
+        if c == '\n' || c == '\r' {
+            break;
+        }
+        else if c == '\\' && sub[idx + 1..].chars().next().is_some_and(|next| is_escapable(&next)){
+            chars.next();
+            previous = c;
+        }
+        else if !previous.is_whitespace() && sub[idx..].starts_with(delimiter){
+            return Some(idx);
+        }
+        else {
+            previous = c;
+        }
+    }
+    None
+}
+
+/// A function to check whether the style
+/// delimiter at the start of the supplied string
+/// opens an inline text style. This is the case if
+/// the style is not open yet, the delimiter is
+/// followed by a character that is not whitespace,
+/// and a closing delimiter follows on the same line.
+/// An underline delimiter around a single word
+/// made up of letters, digits, and underscores is
+/// part of an identifier like `__init__` and does
+/// not open a style. A boolean reflecting this
+/// is returned.
+pub fn opens_style(
+    sub: &str,
+    style: &TokenType,
+    next: &Option<char>,
+    cursor: &mut LexCursor
+) -> bool {
+    if cursor.open_styles.contains(style) ||
+        cursor.unclosed_styles.contains(style) ||
+        !next.is_some_and(|c| !c.is_whitespace()){
+        return false;
+    }
+    match closing_style_offset(sub){
+        Some(idx) => *style != TokenType::UnderlineText || !sub[2..idx]
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_'),
+        None => {
+            cursor.unclosed_styles.push(style.clone());
+            false
+        }
+    }
+}
+
+/// A function to resolve the escape
+/// sequences inside a string of text
+/// lexed from Jirai source code and
+/// return the literal text.
+pub fn unescape(
+    sub: &str
+) -> String {
+    let mut result: String = String::new();
+    let mut chars = sub.chars().peekable();
+    while let Some(c) = chars.next(){
+        match chars.peek(){
+            Some(next) if c == '\\' && is_escapable(next) => {
+                result.push(*next);
+                chars.next();
+            },
+            _ => result.push(c)
+        }
+    }
+    result
+}
+
+/// A function to map a single reserved
+/// character onto the type of token it
+/// stands for. If the character does not
+/// stand for a token on its own, `None`
+/// is returned.
+pub fn reserved_token_type(
+    sub: &char
+) -> Option<TokenType> {
+    match sub {
+        '~' => Some(TokenType::ListMarker),
+        '>' => Some(TokenType::CloseAngle),
+        '<' => Some(TokenType::OpenAngle),
+        '[' => Some(TokenType::OpenSquare),
+        ']' => Some(TokenType::CloseSquare),
+        '{' => Some(TokenType::OpenCurly),
+        '}' => Some(TokenType::CloseCurly),
+        '$' => Some(TokenType::ItalicText),
+        '*' => Some(TokenType::BoldText),
+        '@' => Some(TokenType::ImageMarker),
+        '#' => Some(TokenType::LinkMarker),
+        '(' => Some(TokenType::OpenBracket),
+        ')' => Some(TokenType::CloseBracket),
+        _ => None
+    }
+}
+
+/// An enumeration that lists
+/// the modes the lexer can be in.
+/// In the `Code` and `Quote` modes,
+/// everything up to the closing symbol
+/// of inline code or an inline quote
+/// is lexed as one raw string. In the
+/// `CodeBlock` mode, every line up to
+/// the closing fence of a code block is
+/// lexed as one raw string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LexMode{
+    Code,
+    Quote,
+    Normal,
+    CodeBlock
+}
+
+/// A structure to keep track of
+/// where the lexer currently is inside
+/// a string of Jirai source code. The
+/// `offset` field holds a byte offset,
+/// whereas the `line` and `column` fields
+/// count lines and characters. The `mode`
+/// field holds the mode the next token
+/// is lexed in. The `table_row` field holds
+/// whether the current line is a row of a
+/// table, in which the `|` character
+/// separates cells. The `open_styles` field
+/// holds the inline text styles opened on
+/// the current line and the `unclosed_styles`
+/// field the styles that have no closing
+/// delimiter left on the current line. The
+/// `searched` field holds how many bytes after
+/// the offset have already been searched for the
+/// end of a comment or a code block that runs past
+/// the end of the source supplied so far, so that
+/// the search can carry on from there once more
+/// source is supplied. The `unclosed_blocks` field
+/// holds the comments and code blocks known to
+/// have no end in the rest of the final source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LexCursor{
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+    pub mode: LexMode,
+    pub searched: usize,
+    pub table_row: bool,
+    pub open_styles: Vec<TokenType>,
+    pub unclosed_styles: Vec<TokenType>,
+    pub unclosed_blocks: Vec<TokenType>
+}
+
+/// Implementing functions
+/// for the `LexCursor`
+/// structure.
+impl LexCursor {
+
+    /// A function to create a
+    /// new instance of the `LexCursor`
+    /// structure and return it.
+    pub fn new(
+        line: &usize,
+        column: &usize,
+        offset: &usize
+    ) -> LexCursor {
+        LexCursor{
+            line: *line,
+            column: *column,
+            offset: *offset,
+            mode: LexMode::Normal,
+            searched: 0,
+            table_row: false,
+            open_styles: Vec::new(),
+            unclosed_styles: Vec::new(),
+            unclosed_blocks: Vec::new()
+        }
+    }
+
+    /// A function to return the
+    /// current line and column of
+    /// the cursor as a `Position`.
+    pub fn position(
+        &self
+    ) -> Position {
+        Position::new(&self.line, &self.column)
+    }
+}
+
+/// A function to measure the indentation
+/// at the supplied byte offset of the source.
+/// If the offset is at the start of a line
+/// and the line starts with spaces or tabs
+/// followed by anything but a line break,
+/// the length of that whitespace in bytes
+/// is returned. Otherwise, `None` is returned,
+/// so blank lines carry no indentation.
+pub fn indentation_length(
+    source: &str,
+    offset: &usize
+) -> Option<usize> {
+    let at_line_start: bool = source[..*offset].is_empty() ||
+        source[..*offset].ends_with(['\n', '\r']);
+    let rest: &str = &source[*offset..];
+    let length: usize = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    let followed_by_content: bool = rest[length..]
         .chars()
-        .collect::<Vec<char>>();
-    if chars.len() == 0{
-        Err::<Vec<Token>, JiraiErr>(
-            JiraiErr::new("Source cannot be empty.")
-        )
+        .next()
+        .is_some_and(|next| next != '\n' && next != '\r');
+    Some(length).filter(|length| at_line_start && *length > 0 && followed_by_content)
+}
+
+/// A function to measure the marker of
+/// an ordered list item at the supplied byte
+/// offset of the source. A marker is either
+/// `#~` for automatic numbering or a number
+/// followed by `~`, and it can only start a
+/// line, possibly after its indentation. The
+/// length of the marker in bytes is returned.
+/// If there is no marker at the offset, `None`
+/// is returned.
+pub fn ordered_marker_length(
+    source: &str,
+    offset: &usize
+) -> Option<usize> {
+    let before: &str = source[..*offset].trim_end_matches([' ', '\t']);
+    let at_line_start: bool = before.is_empty() || before.ends_with(['\n', '\r']);
+    let rest: &str = &source[*offset..];
+    let number_length: usize = if rest.starts_with('#') {
+        1
     }
     else {
-        let mut result: Vec<Token> = Vec::new();
-        let mut cursor: usize = 0;
-        let mut line_count: usize = 0;
-        let mut column_count: usize = 0;
-        while cursor < chars.len(){
-
-            if chars.get(cursor) == Some(&'<') &&
-               chars.get(cursor + 1) == Some(&'3')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 2)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::HeadingMarker,
-                        &None
-                    )
-                );
-                cursor += 2;
-                column_count += 2;
-            }
-            else if chars.get(cursor) == Some(&'(') &&
-                chars.get(cursor + 1) == Some(&'^') &&
-                chars.get(cursor + 2) == Some(&'-') &&
-                chars.get(cursor + 3) == Some(&'^') &&
-                chars.get(cursor + 4) == Some(&')')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 5)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::DocumentLimiter,
-                        &None
-                    )
-                );
-                cursor += 5;
-                column_count += 5;
-            }
-            else if is_text(&chars[cursor])
-            {
-                let column_count_start = column_count;
-                let cursor_start = cursor;
-                let mut char_buf: Vec<char> = Vec::new();
-                while let Some(&c) = chars.get(cursor){
-                    if is_text(&c){
-                        char_buf.push(c);
-                        cursor += 1;
-                    }
-                    else {
-                        break;
-                    }
-                }
-                let consumed_length = cursor - cursor_start;
-                column_count += consumed_length;
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count)),
-                        &Position::new(&line_count, &column_count_start),
-                        &TokenType::UserString,
-                        &Some(char_buf.into_iter().collect::<String>())
-                    )
-                );
-            }
-            else if chars.get(cursor) == Some(&'~')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::ListMarker,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
+        rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len()
+    };
+    let is_marker: bool = number_length > 0 && rest[number_length..].starts_with('~');
+    Some(number_length + 1).filter(|_| at_line_start && is_marker)
+}
 
-            }
-            else if chars.get(cursor) == Some(&'>')
-            {
-               result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::CloseAngle,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
+/// A function to return the byte offset
+/// of the end of the first line of the
+/// supplied string, which is the offset of
+/// its first line break or its length.
+pub fn line_end_offset(
+    sub: &str
+) -> usize {
+    sub.find(['\n', '\r']).unwrap_or(sub.len())
+}
 
-            }
-            else if chars.get(cursor) == Some(&'<')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::OpenAngle,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
-            }
-            else if chars.get(cursor) == Some(&'[')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::OpenSquare,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
-            }
-            else if chars.get(cursor) == Some(&']')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::CloseSquare,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
+/// A function to search the first line
+/// of the supplied string for the supplied
+/// closing symbol. Only the text up to the
+/// symbol is looked at, so the search does not
+/// run to the end of a long line. If the symbol
+/// is found, its byte offset is returned. If it
+/// is not, the byte offset of the end of the
+/// line is returned as an error.
+pub fn find_on_line(
+    sub: &str,
+    closer: &str
+) -> Result<usize, usize> {
+    for (idx, c) in sub.char_indices(){
+        if c == '\n' || c == '\r' {
+            return Err(idx);
+        }
+        if sub[idx..].starts_with(closer){
+            return Ok(idx);
+        }
+    }
+    Err(sub.len())
+}
 
-            }
-            else if chars.get(cursor) == Some(&'{')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::OpenCurly,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
+/// A function to check whether the supplied
+/// byte offset of the source is at the start
+/// of a line, possibly after its indentation.
+/// A boolean reflecting this is returned.
+pub fn is_line_start(
+    source: &str,
+    offset: &usize
+) -> bool {
+    let before: &str = source[..*offset].trim_end_matches([' ', '\t']);
+    before.is_empty() || before.ends_with(['\n', '\r'])
+}
 
+/// A function to measure the opening fence
+/// of a code block at the supplied byte offset
+/// of the source. An opening fence is the `<<<`
+/// symbol at the start of a line, possibly after
+/// its indentation. The rest of the line is the
+/// info string of the code block, whose first word
+/// names the language of the code. The length
+/// of the fence in bytes, including its line
+/// break, is returned. If there is no opening
+/// fence at the offset, `None` is returned.
+pub fn opening_fence_length(
+    source: &str,
+    offset: &usize
+) -> Option<usize> {
+    let rest: &str = &source[*offset..];
+    if !rest.starts_with("<<<") || !is_line_start(source, offset){
+        return None;
+    }
+    let line_end: usize = line_end_offset(rest);
+    let break_length: usize = if rest[line_end..].starts_with("\r\n") {
+        2
+    }
+    else {
+        rest[line_end..].len().min(1)
+    };
+    Some(line_end + break_length)
+}
+
+/// A function to check whether the supplied
+/// byte offset of the source starts a divider,
+/// which is a line made up of nothing but one
+/// or more broken hearts, the `</3` symbol,
+/// possibly after its indentation. A boolean
+/// reflecting this is returned.
+pub fn is_divider(
+    source: &str,
+    offset: &usize
+) -> bool {
+    let rest: &str = &source[*offset..];
+    if !rest.starts_with("</3") || !is_line_start(source, offset){
+        return false;
+    }
+    let line: &str = rest[..line_end_offset(rest)].trim_end();
+    line.len().is_multiple_of(3) && line.as_bytes().chunks(3).all(|heart| heart == b"</3")
+}
+
+/// A function to check whether the supplied
+/// line is the closing fence of a code block,
+/// which is the `>>>` symbol standing on a line
+/// of its own, possibly after its indentation.
+/// A boolean reflecting this is returned.
+pub fn is_closing_fence(
+    line: &str
+) -> bool {
+    line.trim() == ">>>"
+}
+
+/// A function to find the closing fence of
+/// a code block inside the supplied source,
+/// which has to start at the start of a line.
+/// The byte offset of the start of the line
+/// holding the closing fence is returned. If
+/// there is no closing fence, `None` is returned.
+pub fn closing_fence_offset(
+    sub: &str
+) -> Option<usize> {
+    let mut line_start: usize = 0;
+    loop {
+        let line_end: usize = sub[line_start..]
+            .find(['\n', '\r'])
+            .map(|idx| line_start + idx)
+            .unwrap_or(sub.len());
+        if is_closing_fence(&sub[line_start..line_end]){
+            return Some(line_start);
+        }
+        if line_end == sub.len(){
+            return None;
+        }
+        line_start = line_end + if sub[line_end..].starts_with("\r\n") { 2 } else { 1 };
+    }
+}
+
+/// A function to return the width of
+/// the supplied indentation. A tab counts
+/// as four spaces.
+pub fn indentation_width(
+    indentation: &str
+) -> usize {
+    indentation
+        .chars()
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+/// A function to count the line
+/// breaks inside the supplied string.
+/// A carriage return followed by a line
+/// feed counts as a single line break.
+pub fn count_line_breaks(
+    sub: &str
+) -> usize {
+    sub.matches('\n').count() + sub.matches('\r').count() - sub.matches("\r\n").count()
+}
+
+/// A function to lex the token starting
+/// at the cursor's offset inside the supplied
+/// source and advance the cursor past it. The
+/// value of the token borrows from the source.
+/// If the end of the source has been reached,
+/// `None` is returned. `None` is also returned
+/// without advancing the cursor if the source
+/// is not final and a token runs past its end,
+/// so that more source can be supplied. If an
+/// illegal character or an unclosed construct
+/// is detected, a token of the `Error` type
+/// covering the offending text is returned
+/// together with a diagnostic describing it,
+/// and lexing can carry on after it.
+pub fn scan_token_recovering<'a>(
+    source: &'a str,
+    cursor: &mut LexCursor,
+    is_final: &bool
+) -> Option<(BorrowedToken<'a>, Option<Diagnostic>)> {
+    let rest: &'a str = source.get(cursor.offset..)?;
+    let current: char = rest.chars().next()?;
+    let start: LexCursor = cursor.clone();
+    let mut value: Option<&'a str> = None;
+    let mut verbatim: Option<usize> = None;
+    let mut message: Option<String> = None;
+    let token_type: TokenType;
+    let consumed_bytes: usize;
+    let mut line_end: usize = 0;
+    if cursor.mode == LexMode::CodeBlock {
+        if let Some(offset) = closing_fence_offset(rest){
+            let break_length: usize = if rest[..offset].ends_with("\r\n") {
+                2
             }
-            else if chars.get(cursor) == Some(&'}')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::CloseCurly,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
-            }
-            else if chars.get(cursor) == Some(&'$')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::ItalicText,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
-            }
-            else if chars.get(cursor) == Some(&'*')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::BoldText,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
-            } 
-            else if chars.get(cursor) == Some(&'@')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::ImageMarker,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
-            }
-            else if chars.get(cursor) == Some(&'#')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::LinkMarker,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
-            }
-            else if chars.get(cursor) == Some(&'\n')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::NewLine,
-                        &None
+            else {
+                offset.min(1)
+            };
+            verbatim = Some(offset - break_length).filter(|length| *length > 0);
+        }
+        cursor.mode = LexMode::Normal;
+    }
+    else if cursor.mode != LexMode::Normal {
+        let (closer, name): (&str, &str) = match cursor.mode {
+            LexMode::Code => (">", "inline code"),
+            _ => (")<", "quote")
+        };
+        match find_on_line(rest, closer){
+            Ok(length) => verbatim = Some(length).filter(|length| *length > 0),
+            Err(length) => {
+                line_end = length;
+                message = Some(
+                    format!(
+                        "Unclosed {} at position \"{}\"!",
+                        name,
+                        &cursor.position()
                     )
                 );
-                cursor += 1;
-                column_count += 1;
-                line_count += 1;
             }
-            else if chars.get(cursor) == Some(&'\r')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::NewLine,
-                        &None
+        };
+        cursor.mode = LexMode::Normal;
+    }
+    if message.is_some(){
+        token_type = TokenType::Error;
+        value = Some(&rest[..line_end]);
+        consumed_bytes = line_end;
+    }
+    else if let Some(length) = verbatim {
+        token_type = TokenType::RawString;
+        value = Some(&rest[..length]);
+        consumed_bytes = length;
+    }
+    else if is_divider(source, &cursor.offset){
+        token_type = TokenType::Divider;
+        consumed_bytes = line_end_offset(rest);
+    }
+    else if rest.starts_with("<3"){
+        token_type = TokenType::HeadingMarker;
+        consumed_bytes = 2;
+    }
+    else if rest.starts_with("(^-^)"){
+        token_type = TokenType::DocumentLimiter;
+        consumed_bytes = 5;
+    }
+    else if let Some(body) = rest.strip_prefix("(~.~("){
+        let from: usize = body.floor_char_boundary(cursor.searched.saturating_sub(9));
+        let closer: Option<usize> = if cursor.unclosed_blocks.contains(&TokenType::Comment){
+            None
+        }
+        else {
+            body[from..].find(")~.~)").map(|idx| from + idx)
+        };
+        match closer {
+            Some(length) => {
+                token_type = TokenType::Comment;
+                value = Some(&body[..length]);
+                consumed_bytes = length + 10;
+            },
+            None if !is_final => {
+                cursor.searched = rest.len();
+                return None;
+            },
+            None => {
+                if !cursor.unclosed_blocks.contains(&TokenType::Comment){
+                    cursor.unclosed_blocks.push(TokenType::Comment);
+                }
+                message = Some(
+                    format!(
+                        "Unclosed comment at position \"{}\"!",
+                        &cursor.position()
                     )
                 );
-                cursor += 1;
-                column_count += 1;
-                line_count += 1;
+                token_type = TokenType::Error;
+                value = Some(&rest[..5]);
+                consumed_bytes = 5;
             }
-            else if chars.get(cursor) == Some(&'\r') &&
-                chars.get(cursor + 1) == Some(&'\n')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 2)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::NewLine,
-                        &None
+        };
+    }
+    else if rest.starts_with("(~.~)"){
+        line_end = line_end_offset(rest);
+        token_type = TokenType::Comment;
+        value = Some(&rest[5..line_end]);
+        consumed_bytes = line_end;
+    }
+    else if let Some(length) = opening_fence_length(source, &cursor.offset){
+        line_end = line_end_offset(rest);
+        let searched: usize = rest.floor_char_boundary(cursor.searched).max(length);
+        let from: usize = rest[length..searched]
+            .rfind(['\n', '\r'])
+            .map(|idx| length + idx + 1)
+            .unwrap_or(length);
+        let closer: Option<usize> = if cursor.unclosed_blocks.contains(&TokenType::OpenFence){
+            None
+        }
+        else {
+            closing_fence_offset(&rest[from..])
+        };
+        match closer {
+            Some(_) => {
+                token_type = TokenType::OpenFence;
+                value = Some(rest[3..line_end].trim()).filter(|info| !info.is_empty());
+                cursor.mode = LexMode::CodeBlock;
+                consumed_bytes = length;
+            },
+            None if !is_final => {
+                cursor.searched = rest.len();
+                return None;
+            },
+            None => {
+                if !cursor.unclosed_blocks.contains(&TokenType::OpenFence){
+                    cursor.unclosed_blocks.push(TokenType::OpenFence);
+                }
+                message = Some(
+                    format!(
+                        "Unclosed code block at position \"{}\"!",
+                        &cursor.position()
                     )
                 );
-                cursor += 2;
-                column_count += 2;
-                line_count += 1;
+                token_type = TokenType::Error;
+                value = Some(&rest[..line_end]);
+                consumed_bytes = line_end;
             }
-            else if chars.get(cursor) == Some(&'(')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::OpenBracket,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
+        };
+    }
+    else if rest.starts_with(">>>") && is_line_start(source, &cursor.offset) &&
+        is_closing_fence(&rest[..line_end_offset(rest)]){
+        token_type = TokenType::CloseFence;
+        consumed_bytes = line_end_offset(rest);
+    }
+    else if let Some(quote) = rest.strip_prefix(">("){
+        token_type = TokenType::OpenQuote;
+        let quoted: Option<char> = quote.chars().find(|c| *c != ' ' && *c != '\t');
+        if quoted.is_some_and(|c| c != '\n' && c != '\r'){
+            cursor.mode = LexMode::Quote;
+        }
+        consumed_bytes = 2;
+    }
+    else if rest.starts_with(")<"){
+        token_type = TokenType::CloseQuote;
+        consumed_bytes = 2;
+    }
+    else if let Some(length) = indentation_length(source, &cursor.offset){
+        token_type = TokenType::Indent;
+        value = Some(&rest[..length]);
+        consumed_bytes = length;
+    }
+    else if let Some(length) = ordered_marker_length(source, &cursor.offset){
+        token_type = TokenType::OrderedListMarker;
+        value = Some(&rest[..length - 1]);
+        consumed_bytes = length;
+    }
+    else if let Some(style) = style_token_type(rest){
+        let previous: Option<char> = source[..cursor.offset].chars().next_back();
+        let next: Option<char> = rest[2..].chars().next();
+        if cursor.open_styles.contains(&style) && previous.is_some_and(|c| !c.is_whitespace()){
+            cursor.open_styles.retain(|open| *open != style);
+            token_type = style;
+        }
+        else if opens_style(rest, &style, &next, cursor){
+            cursor.open_styles.push(style.clone());
+            token_type = style;
+        }
+        else {
+            token_type = TokenType::UserString;
+            value = Some(&rest[..2]);
+        }
+        consumed_bytes = 2;
+    }
+    else if current == '|' && (cursor.table_row || is_line_start(source, &cursor.offset)){
+        token_type = TokenType::TableDelimiter;
+        cursor.table_row = true;
+        consumed_bytes = 1;
+    }
+    else if is_text(&current){
+        let mut chars = rest.char_indices().peekable();
+        let mut length: usize = 0;
+        while let Some((idx, c)) = chars.next(){
+            let escaped: bool = c == '\\' && chars
+                .peek()
+                .is_some_and(|(_, next)| is_escapable(next));
+            if escaped {
+                let (next_idx, next) = chars.next()?;
+                length = next_idx + next.len_utf8();
             }
-            else if chars.get(cursor) == Some(&')')
-            {
-                result.push(
-                    Token::new(
-                        &Position::new(&line_count, &(column_count + 1)),
-                        &Position::new(&line_count, &column_count),
-                        &TokenType::CloseBracket,
-                        &None
-                    )
-                );
-                cursor += 1;
-                column_count += 1;
+            else if is_text(&c) && style_token_type(&rest[idx..]).is_none() &&
+                !(c == '|' && cursor.table_row){
+                length = idx + c.len_utf8();
             }
             else {
-                let e: String = format!(
-                    "Unexpected character(s) at position \"{}:{}\": \"{}\"!", 
-                    &line_count, 
-                    &column_count,
-                    &chars[cursor]
-                );
-                return Err::<Vec<Token>, JiraiErr>(
-                    JiraiErr::new(&e.to_string())
-                );
+                break;
             }
         }
-        Ok(result)
+        token_type = TokenType::UserString;
+        value = Some(&rest[..length]);
+        consumed_bytes = length;
+    }
+    else if rest.starts_with("\r\n"){
+        token_type = TokenType::NewLine;
+        cursor.table_row = false;
+        cursor.open_styles.clear();
+        cursor.unclosed_styles.clear();
+        consumed_bytes = 2;
+    }
+    else if current == '\n' || current == '\r' {
+        token_type = TokenType::NewLine;
+        cursor.table_row = false;
+        cursor.open_styles.clear();
+        cursor.unclosed_styles.clear();
+        consumed_bytes = 1;
+    }
+    else if let Some(reserved) = reserved_token_type(&current){
+        if reserved == TokenType::OpenAngle {
+            cursor.mode = LexMode::Code;
+        }
+        token_type = reserved;
+        consumed_bytes = 1;
+    }
+    else {
+        message = Some(
+            format!(
+                "Unexpected character(s) at position \"{}:{}\": \"{}\"!", 
+                &cursor.line, 
+                &cursor.column,
+                &current.escape_default()
+            )
+        );
+        token_type = TokenType::Error;
+        value = Some(&rest[..current.len_utf8()]);
+        consumed_bytes = current.len_utf8();
+    }
+    let consumed: &str = &rest[..consumed_bytes];
+    let line_breaks: usize = count_line_breaks(consumed);
+    cursor.offset += consumed_bytes;
+    cursor.searched = 0;
+    cursor.column += consumed.chars().count();
+    if token_type != TokenType::NewLine {
+        cursor.line += line_breaks;
+    }
+    let token: BorrowedToken<'a> = BorrowedToken::new(
+        &Span::new(&cursor.offset, &start.offset),
+        &cursor.position(),
+        &start.position(),
+        &token_type,
+        &value
+    );
+    if token_type == TokenType::NewLine {
+        cursor.line += line_breaks;
+    }
+    let diagnostic: Option<Diagnostic> = message.map(
+        |message| Diagnostic::new(&token.span, &token.end, &token.start, &message)
+    );
+    Some((token, diagnostic))
+}
+
+/// A function to lex the token starting
+/// at the cursor's offset inside the supplied
+/// source and advance the cursor past it. The
+/// value of the token borrows from the source.
+/// If the end of the source has been reached,
+/// `None` is returned. `None` is also returned
+/// without advancing the cursor if the source
+/// is not final and a token runs past its end,
+/// so that more source can be supplied. If an
+/// illegal character or an unclosed construct
+/// is detected, an error is returned.
+pub fn scan_token<'a>(
+    source: &'a str,
+    cursor: &mut LexCursor,
+    is_final: &bool
+) -> Option<Result<BorrowedToken<'a>, JiraiErr>> {
+    let (token, diagnostic) = scan_token_recovering(source, cursor, is_final)?;
+    match diagnostic {
+        Some(diagnostic) => Some(Err::<BorrowedToken<'a>, JiraiErr>(JiraiErr::from(diagnostic))),
+        None => Some(Ok(token))
+    }
+}
+
+/// A function to split a string
+/// of Jirai source into a vector
+/// of instances of the `BorrowedToken`
+/// structure without copying any text.
+/// If the tokenization of a Jirai source
+/// code string is successful, this vector
+/// is returned. If an illegal character 
+/// is detected, an error is returned.
+pub fn tokenize_borrowed<'a>(
+    sub: &'a str
+) -> Result<Vec<BorrowedToken<'a>>, JiraiErr> {
+    if sub.is_empty(){
+        return Err::<Vec<BorrowedToken<'a>>, JiraiErr>(
+            JiraiErr::new("Source cannot be empty.")
+        );
+    }
+    let mut result: Vec<BorrowedToken<'a>> = Vec::new();
+    let mut cursor: LexCursor = LexCursor::new(&0, &0, &0);
+    while let Some(token) = scan_token(sub, &mut cursor, &true){
+        result.push(token?);
+    }
+    Ok(result)
+}
+
+/// A function to split a string
+/// of Jirai source into a vector
+/// of instances of the `Token` structure.
+/// If the tokenization of a Jirai source
+/// code string is successful, this vector
+/// is returned. If an illegal character 
+/// is detected, an error is returned.
+/// A reserved character preceded by a
+/// backslash is read as literal text.
+pub fn tokenize_string(
+    sub: &str
+) -> Result<Vec<Token>, JiraiErr> {
+    let tokens: Vec<Token> = tokenize_borrowed(sub)?
+        .into_iter()
+        .map(Token::from)
+        .collect::<Vec<Token>>();
+    Ok(tokens)
+}
+
+/// A data structure holding
+/// the tokens lexed from a piece
+/// of Jirai source code in recovery
+/// mode together with every lex
+/// error found along the way.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecoveredTokens {
+    pub tokens: Vec<Token>,
+    pub errors: Vec<Diagnostic>
+}
+
+/// A function to split a string
+/// of Jirai source into a vector
+/// of instances of the `Token` structure
+/// without stopping at the first error.
+/// Each illegal character or unclosed
+/// construct becomes a token of the
+/// `Error` type and a diagnostic, and
+/// tokenizing carries on after it. The
+/// tokens and the diagnostics are returned
+/// in an instance of the `RecoveredTokens`
+/// structure.
+pub fn tokenize_recovering(
+    sub: &str
+) -> RecoveredTokens {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut errors: Vec<Diagnostic> = Vec::new();
+    if sub.is_empty(){
+        let origin: Position = Position::new(&0, &0);
+        errors.push(
+            Diagnostic::new(
+                &Span::new(&0, &0),
+                &origin,
+                &origin,
+                "Source cannot be empty."
+            )
+        );
+    }
+    let mut cursor: LexCursor = LexCursor::new(&0, &0, &0);
+    while let Some((token, diagnostic)) = scan_token_recovering(sub, &mut cursor, &true){
+        tokens.push(Token::from(token));
+        errors.extend(diagnostic);
+    }
+    RecoveredTokens {
+        tokens,
+        errors
     }
 }
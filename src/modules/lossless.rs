@@ -0,0 +1,332 @@
+/*
+Jirai by Alyx Shang.
+Licensed under the FSL v1.
+*/
+
+/// Importing the function to
+/// move a value out of a mutable
+/// reference.
+use std::mem::take;
+
+/// Importing the structure
+/// holding information on
+/// a capture token.
+use super::lexer::Token;
+
+/// Importing the data structure
+/// to encapsulate data about the
+/// byte offsets of a token.
+use super::lexer::Span;
+
+/// Importing the structure
+/// to catch and handle errors.
+use super::err::JiraiErr;
+
+/// Importing the enumeration
+/// containing all possible types
+/// Jirai tokens.
+use super::lexer::TokenType;
+
+/// Importing the structure
+/// holding information on a
+/// token borrowing from the source.
+use super::lexer::BorrowedToken;
+
+/// Importing the function to
+/// tokenize a string of Jirai
+/// source code without copying.
+use super::lexer::tokenize_borrowed;
+
+/// An enumeration that
+/// lists all possible types
+/// of trivia attached to tokens
+/// in a lossless token stream.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TriviaType{
+    Comment,
+    BlankLine,
+    Whitespace,
+    Indentation
+}
+
+/// A structure encapsulating
+/// a piece of Jirai source code
+/// that carries no meaning but is
+/// needed to rebuild the source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trivia{
+    pub span: Span,
+    pub text: String,
+    pub trivia_type: TriviaType
+}
+
+/// Implementing functions for
+/// the `Trivia` structure.
+impl Trivia {
+
+    /// Implementing a function
+    /// to create a new instance
+    /// of the `Trivia` structure
+    /// and return that instance.
+    pub fn new(
+        span: &Span,
+        text: &str,
+        trivia_type: &TriviaType
+    ) -> Trivia {
+        Trivia{
+            span: span.clone(),
+            text: text.to_string(),
+            trivia_type: trivia_type.clone()
+        }
+    }
+}
+
+/// A structure encapsulating a
+/// token together with the raw source
+/// text it was lexed from and the trivia
+/// surrounding it. Concatenating the
+/// leading trivia, the raw text, and the
+/// trailing trivia of every token in a
+/// lossless token stream gives back the
+/// original source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LosslessToken{
+    pub raw: String,
+    pub token: Token,
+    pub leading: Vec<Trivia>,
+    pub trailing: Vec<Trivia>
+}
+
+/// Implementing functions for
+/// the `LosslessToken` structure.
+impl LosslessToken {
+
+    /// Implementing a function
+    /// to create a new instance
+    /// of the `LosslessToken` structure
+    /// from a borrowed token and return
+    /// that instance.
+    pub fn new(
+        token: &BorrowedToken,
+        source: &str,
+        leading: &[Trivia]
+    ) -> Result<LosslessToken, JiraiErr> {
+        Ok(
+            LosslessToken{
+                raw: token.span.slice(source)?.to_string(),
+                token: token.to_token(),
+                leading: leading.to_vec(),
+                trailing: Vec::new()
+            }
+        )
+    }
+
+    /// Implementing a function to
+    /// return the source text this
+    /// token was lexed from, including
+    /// its trivia.
+    pub fn to_source(
+        &self
+    ) -> String {
+        let mut result: String = String::new();
+        for trivia in &self.leading {
+            result.push_str(&trivia.text);
+        }
+        result.push_str(&self.raw);
+        for trivia in &self.trailing {
+            result.push_str(&trivia.text);
+        }
+        result
+    }
+}
+
+/// A function to rebuild Jirai
+/// source code from a lossless
+/// stream of tokens and return it.
+pub fn tokens_to_source(
+    tokens: &[LosslessToken]
+) -> String {
+    tokens
+        .iter()
+        .map(|token| token.to_source())
+        .collect::<String>()
+}
+
+/// A function to cut the bytes from
+/// `start` to `end` out of a text token's
+/// value and return them as a new token
+/// with updated offsets and positions.
+pub fn slice_token<'a>(
+    token: &BorrowedToken<'a>,
+    start: &usize,
+    end: &usize
+) -> BorrowedToken<'a> {
+    let raw: &'a str = token.value.unwrap_or("");
+    let mut sliced: BorrowedToken<'a> = token.clone();
+    sliced.span = Span::new(&(token.span.start + end), &(token.span.start + start));
+    sliced.start.column = token.start.column + raw[..*start].chars().count();
+    sliced.end.column = token.start.column + raw[..*end].chars().count();
+    sliced.value = Some(&raw[*start..*end]);
+    sliced
+}
+
+/// A function to check whether
+/// the supplied token is text made
+/// up of whitespace only.
+pub fn is_blank(
+    token: &BorrowedToken
+) -> bool {
+    token.token_type == TokenType::UserString &&
+        token.value.is_some_and(|value| value.trim().is_empty())
+}
+
+/// A function to check whether
+/// the supplied token is the
+/// indentation of a line.
+pub fn is_indent(
+    token: &BorrowedToken
+) -> bool {
+    token.token_type == TokenType::Indent
+}
+
+/// A function to check whether
+/// the supplied token is a comment.
+pub fn is_comment(
+    token: &BorrowedToken
+) -> bool {
+    token.token_type == TokenType::Comment
+}
+
+/// A function to split a string of Jirai
+/// source into a vector of instances of the
+/// `LosslessToken` structure. Leading indentation
+/// and trailing whitespace on a line are split
+/// off text tokens and attached as trivia. Comments
+/// are attached as trivia of the token after them,
+/// or of the token before them at the end of a
+/// line, together with any whitespace following
+/// them. Blank lines and lines holding nothing but
+/// comments are attached as leading trivia of the
+/// next token. If the tokenization of
+/// a Jirai source code string is successful,
+/// this vector is returned. If an illegal
+/// character is detected, an error is returned.
+pub fn tokenize_lossless(
+    sub: &str
+) -> Result<Vec<LosslessToken>, JiraiErr> {
+    let tokens: Vec<BorrowedToken> = tokenize_borrowed(sub)?;
+    let mut result: Vec<LosslessToken> = Vec::new();
+    let mut pending: Vec<Trivia> = Vec::new();
+    for line in tokens.split_inclusive(|token| token.token_type == TokenType::NewLine){
+        let (body, newline): (&[BorrowedToken], Option<&BorrowedToken>) = match line.split_last(){
+            Some((last, body)) if last.token_type == TokenType::NewLine => (body, Some(last)),
+            _ => (line, None)
+        };
+        if body.iter().all(|token| is_blank(token) || is_comment(token) || is_indent(token)){
+            let span: Span = Span::new(&line[line.len() - 1].span.end, &line[0].span.start);
+            let trivia_type: TriviaType = if body.iter().any(is_comment){
+                TriviaType::Comment
+            }
+            else {
+                TriviaType::BlankLine
+            };
+            pending.push(Trivia::new(&span, span.slice(sub)?, &trivia_type));
+            continue;
+        }
+        let line_start: usize = result.len();
+        let first: usize = body
+            .iter()
+            .position(|token| !is_comment(token) && !is_indent(token))
+            .unwrap_or(0);
+        let last: usize = body
+            .iter()
+            .rposition(|token| !is_comment(token) && !is_blank(token))
+            .unwrap_or(0);
+        for (idx, token) in body.iter().enumerate(){
+            let mut token: BorrowedToken = token.clone();
+            let mut trailing: Option<Trivia> = None;
+            if is_indent(&token){
+                pending.push(
+                    Trivia::new(
+                        &token.span,
+                        token.span.slice(sub)?,
+                        &TriviaType::Indentation
+                    )
+                );
+                continue;
+            }
+            if is_comment(&token) || (idx > last && is_blank(&token)){
+                let trivia_type: TriviaType = if is_comment(&token){
+                    TriviaType::Comment
+                }
+                else {
+                    TriviaType::Whitespace
+                };
+                let trivia: Trivia = Trivia::new(
+                    &token.span,
+                    token.span.slice(sub)?,
+                    &trivia_type
+                );
+                match result[line_start..].last_mut(){
+                    Some(previous) if idx > last => previous.trailing.push(trivia),
+                    _ => pending.push(trivia)
+                };
+                continue;
+            }
+            if token.token_type == TokenType::UserString {
+                let raw: &str = token.value.unwrap_or("");
+                let mut start: usize = 0;
+                let mut end: usize = raw.len();
+                if idx == first {
+                    start = raw.len() - raw.trim_start().len();
+                    if start > 0 {
+                        let indentation: BorrowedToken = slice_token(&token, &0, &start);
+                        pending.push(
+                            Trivia::new(
+                                &indentation.span,
+                                indentation.value.unwrap_or(""),
+                                &TriviaType::Indentation
+                            )
+                        );
+                    }
+                }
+                if idx == last {
+                    end = start.max(raw.trim_end().len());
+                    if end < raw.len() {
+                        let whitespace: BorrowedToken = slice_token(&token, &end, &raw.len());
+                        trailing = Some(
+                            Trivia::new(
+                                &whitespace.span,
+                                whitespace.value.unwrap_or(""),
+                                &TriviaType::Whitespace
+                            )
+                        );
+                    }
+                }
+                token = slice_token(&token, &start, &end);
+            }
+            if token.span.is_empty(){
+                match result[line_start..].last_mut(){
+                    Some(previous) => previous.trailing.extend(trailing),
+                    None => pending.extend(trailing)
+                };
+                continue;
+            }
+            let mut lossless: LosslessToken = LosslessToken::new(&token, sub, &take(&mut pending))?;
+            lossless.trailing.extend(trailing);
+            result.push(lossless);
+        }
+        if let Some(newline) = newline {
+            result.push(LosslessToken::new(newline, sub, &take(&mut pending))?);
+        }
+    }
+    if result.is_empty(){
+        for token in &tokens {
+            result.push(LosslessToken::new(token, sub, &[])?);
+        }
+    }
+    else if let Some(last) = result.last_mut(){
+        last.trailing.append(&mut pending);
+    }
+    Ok(result)
+}
@@ -27,6 +27,21 @@ pub mod html;
 /// of Jirai source code.
 pub mod lexer;
 
+/// Exporting the module containing
+/// entities to lazily tokenize Jirai
+/// source code from a reader.
+pub mod stream;
+
+/// Exporting the module containing
+/// entities to re-tokenize edited
+/// Jirai source code incrementally.
+pub mod incremental;
+
+/// Exporting the module containing
+/// entities to tokenize Jirai source
+/// code without losing any text.
+pub mod lossless;
+
 /// Declaring the module
 /// containin this crate's
 /// unit tests.
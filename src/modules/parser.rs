@@ -3,6 +3,15 @@ Jirai by Alyx Shang.
 Licensed under the FSL v1.
 */
 
+/// Importing the structure
+/// for ranges of indices.
+use std::ops::Range;
+
+/// Importing the data structure
+/// to encapsulate data about the
+/// byte offsets of a token.
+use super::lexer::Span;
+
 /// Importing the structure
 /// holding information on
 /// a capture token.
@@ -12,11 +21,27 @@ use super::lexer::Token;
 /// to catch and handle errors.
 use super::err::JiraiErr;
 
+/// Importing the data structure
+/// to encapsulate data about the
+/// line and column information
+/// of a token.
+use super::lexer::Position;
+
+/// Importing the structure
+/// describing a problem found
+/// in Jirai source code.
+use super::err::Diagnostic;
+
 /// Importing the enumeration
 /// containing all possible types
 /// Jirai tokens.
 use super::lexer::TokenType;
 
+/// Importing the function to
+/// measure the width of a line's
+/// indentation.
+use super::lexer::indentation_width;
+
 /// An enumeration
 /// describing which
 /// type of Jirai string
@@ -32,6 +57,41 @@ pub enum SourceType{
     Document
 }
 
+/// A structure to encapsulate
+/// the location of a parsed
+/// statement in Jirai source code.
+/// The span holds the byte offsets
+/// and the positions hold the line
+/// and column information of the
+/// statement's first and last tokens.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Location{
+    pub span: Span,
+    pub end: Position,
+    pub start: Position
+}
+
+/// Implementing functions
+/// for the `Location`
+/// structure.
+impl Location {
+
+    /// A function to create a 
+    /// new instance of the `Location`
+    /// structure and return it.
+    pub fn new(
+        span: &Span,
+        end: &Position,
+        start: &Position
+    ) -> Location {
+        Location {
+            span: span.clone(),
+            end: end.clone(),
+            start: start.clone()
+        }
+    }
+}
+
 /// An enumeration containing
 /// every single type of block
 /// element in Jirai source code.
@@ -39,9 +99,17 @@ pub enum SourceType{
 /// number of sub-statements
 #[derive(PartialEq, Debug, Clone)]
 pub enum Statement{
-    Heading(usize, Vec<InlineStatement>),
-    Paragraph(Vec<InlineStatement>),
-    UnorderedList(Vec<InlineStatement>)
+    Comment(Location, String),
+    Heading(Location, usize, Vec<InlineStatement>),
+    Paragraph(Location, Vec<InlineStatement>),
+    UnorderedList(Location, Vec<ListItem>),
+    OrderedList(Location, usize, Vec<ListItem>),
+    BlockQuote(Location, Vec<Statement>, Option<Vec<InlineStatement>>),
+    CodeBlock(Location, Option<String>, String),
+    Table(Location, Vec<Option<Alignment>>, TableRow, Vec<TableRow>),
+    Divider(Location),
+    FootnoteDefinition(Location, String, Vec<InlineStatement>),
+    DefinitionList(Location, Vec<DefinitionItem>)
 }
 
 /// An enumeration containing
@@ -53,14 +121,133 @@ pub enum Statement{
 /// non-terminals.
 #[derive(PartialEq, Debug, Clone)]
 pub enum InlineStatement{
-    Link(Link),
-    Code(String),
-    Text(String),
-    Image(Image),
-    BlockQuote(String),
-    ListItem(Box<Vec<InlineStatement>>),
-    BoldText(Box<Vec<InlineStatement>>),
-    ItalicText(Box<Vec<InlineStatement>>)
+    Link(Location, Link),
+    Code(Location, String),
+    Text(Location, String),
+    Image(Location, Image),
+    Sample(Location, String),
+    Comment(Location, String),
+    Keyboard(Location, Vec<String>),
+    SoftBreak(Location),
+    Subscript(Location, String),
+    Superscript(Location, String),
+    Quote(Location, String),
+    BoldText(Location, Box<Vec<InlineStatement>>),
+    ItalicText(Location, Box<Vec<InlineStatement>>),
+    HighlightText(Location, Box<Vec<InlineStatement>>),
+    UnderlineText(Location, Box<Vec<InlineStatement>>),
+    FootnoteReference(Location, String),
+    StrikethroughText(Location, Box<Vec<InlineStatement>>)
+}
+
+/// Implementing functions
+/// for the `Statement`
+/// enumeration.
+impl Statement {
+
+    /// A function to return the
+    /// location of the statement
+    /// in the source code.
+    pub fn location(
+        &self
+    ) -> &Location {
+        match self {
+            Statement::Comment(location, _) => location,
+            Statement::Heading(location, _, _) => location,
+            Statement::Paragraph(location, _) => location,
+            Statement::UnorderedList(location, _) => location,
+            Statement::OrderedList(location, _, _) => location,
+            Statement::BlockQuote(location, _, _) => location,
+            Statement::CodeBlock(location, _, _) => location,
+            Statement::Table(location, _, _, _) => location,
+            Statement::Divider(location) => location,
+            Statement::FootnoteDefinition(location, _, _) => location,
+            Statement::DefinitionList(location, _) => location
+        }
+    }
+}
+
+/// Implementing functions
+/// for the `InlineStatement`
+/// enumeration.
+impl InlineStatement {
+
+    /// A function to return the
+    /// location of the statement
+    /// in the source code.
+    pub fn location(
+        &self
+    ) -> &Location {
+        match self {
+            InlineStatement::Link(location, _) => location,
+            InlineStatement::Code(location, _) => location,
+            InlineStatement::Text(location, _) => location,
+            InlineStatement::Image(location, _) => location,
+            InlineStatement::Sample(location, _) => location,
+            InlineStatement::Comment(location, _) => location,
+            InlineStatement::Keyboard(location, _) => location,
+            InlineStatement::Subscript(location, _) => location,
+            InlineStatement::Superscript(location, _) => location,
+            InlineStatement::SoftBreak(location) => location,
+            InlineStatement::Quote(location, _) => location,
+            InlineStatement::BoldText(location, _) => location,
+            InlineStatement::ItalicText(location, _) => location,
+            InlineStatement::HighlightText(location, _) => location,
+            InlineStatement::UnderlineText(location, _) => location,
+            InlineStatement::FootnoteReference(location, _) => location,
+            InlineStatement::StrikethroughText(location, _) => location
+        }
+    }
+}
+
+/// A structure to encapsulate
+/// information on a parsed item
+/// of a list. The contents of an
+/// item that continues onto wrapped
+/// lines are joined by soft breaks.
+/// Lists nested inside the item are
+/// held as its children. For a task item,
+/// the `task` field holds whether the
+/// task is done.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ListItem{
+    pub location: Location,
+    pub task: Option<bool>,
+    pub contents: Vec<InlineStatement>,
+    pub children: Vec<Statement>
+}
+
+/// A structure to encapsulate
+/// information on a parsed term
+/// of a definition list and the
+/// definitions given for it.
+#[derive(PartialEq, Debug, Clone)]
+pub struct DefinitionItem{
+    pub location: Location,
+    pub term: Vec<InlineStatement>,
+    pub definitions: Vec<Vec<InlineStatement>>
+}
+
+/// A structure to encapsulate
+/// information on a parsed row
+/// of a table. Each cell of the
+/// row holds the inline statements
+/// written between its delimiters.
+#[derive(PartialEq, Debug, Clone)]
+pub struct TableRow{
+    pub location: Location,
+    pub cells: Vec<Vec<InlineStatement>>
+}
+
+/// An enumeration listing
+/// the ways the cells of a
+/// column of a table can be
+/// aligned.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Alignment{
+    Left,
+    Right,
+    Center
 }
 
 /// A structure to encapsulate
@@ -80,6 +267,17 @@ pub struct Image{
     pub url: String,
 }
 
+/// A structure holding
+/// the statements parsed from
+/// a stream of tokens in recovery
+/// mode together with every parse
+/// error found along the way.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RecoveredStatements{
+    pub statements: Vec<Statement>,
+    pub errors: Vec<Diagnostic>
+}
+
 /// A structure to hold a stream
 /// of tokens lexed from Jirai source
 /// code and a cursor keeping track of
@@ -87,11 +285,15 @@ pub struct Image{
 /// stream. A `source_type` field is
 /// also included to "decide" on whether
 /// the `DocumentLimiter` type of token
-/// should be respected or not.
+/// should be respected or not. Problems
+/// that do not stop parsing, like ragged
+/// rows of a table, are collected in the
+/// `diagnostics` field.
 pub struct Parser{
     pub cursor: usize,
     pub stream: Vec<Token>,
-    pub source_type: SourceType
+    pub source_type: SourceType,
+    pub diagnostics: Vec<Diagnostic>
 }
 
 /// Implementing functions
@@ -105,19 +307,20 @@ impl Parser {
     /// an error is returned instead.
     pub fn new(
         source_type: &SourceType,
-        stream: &Vec<Token>
+        stream: &[Token]
     ) -> Result<Parser, JiraiErr> {
-        if stream.len() == 0{
-            return Err::<Parser, JiraiErr>(
+        if stream.is_empty(){
+            Err::<Parser, JiraiErr>(
                 JiraiErr::new("Token stream cannot be empty.")
-            );
+            )
         }
         else {
             Ok(
                 Parser {
                     cursor: 0,
-                    stream: stream.clone(),
-                    source_type: source_type.clone()
+                    stream: stream.to_vec(),
+                    source_type: source_type.clone(),
+                    diagnostics: Vec::new()
                 }
             )
         }
@@ -128,7 +331,7 @@ impl Parser {
     /// Nothing is returned.
     pub fn advance(
         &mut self
-    ) -> () {
+    ) {
         self.cursor += 1;
     }
 
@@ -139,7 +342,7 @@ impl Parser {
     pub fn is_done(
         &self
     ) -> bool {
-        &self.cursor == &self.stream.len()
+        self.cursor == self.stream.len()
     }
 
     /// A function to "peek" ahead and retrieve the 
@@ -159,9 +362,9 @@ impl Parser {
             Ok(res)
         }
         else {
-            return Err::<Token, JiraiErr>(
+            Err::<Token, JiraiErr>(
                 JiraiErr::new("End of token stream reached.")
-            );
+            )
         }
     }
 
@@ -192,7 +395,7 @@ impl Parser {
                     &format!(
                         "Expected token of type \"{:?}\" at position {}!", 
                         &token_type, 
-                        &current.start.to_string()
+                        &current.start
                     )
                 )
             )
@@ -206,7 +409,7 @@ impl Parser {
     pub fn starts_with(
         &mut self
     ) -> bool {
-        match self.stream.get(0){
+        match self.stream.first(){
             Some(token) => token.token_type == TokenType::DocumentLimiter,
             None => false
         }
@@ -230,14 +433,21 @@ impl Parser {
     /// The main function to parse the token stream.
     /// If the operation is successful, a stream of
     /// statements is returned. If the operation
-    /// fails, an error is returned.
+    /// fails, an error is returned. For documents,
+    /// the statements of all enveloped documents
+    /// are returned one after the other.
     pub fn parse(
         &mut self
     ) -> Result<Vec<Statement>, JiraiErr>{
+        if self.source_type == SourceType::Document {
+            return Ok(self.parse_documents()?.concat());
+        }
         let mut statements: Vec<Statement> = Vec::new();
         while !self.is_done(){
             let current: Token = self.peek()?;
             match current.token_type{
+                TokenType::NewLine => self.advance(),
+                TokenType::Indent if !self.starts_list_item() => self.advance(),
                 TokenType::HeadingMarker => statements.push(self.parse_heading()?),
                 _ => statements.push(self.parse_block_element()?),
             };
@@ -245,6 +455,297 @@ impl Parser {
         Ok(statements)
     }
 
+    /// A function to parse the token stream
+    /// without stopping at the first error.
+    /// If a block element cannot be parsed, the
+    /// error is recorded as a diagnostic and the
+    /// lines of the block element before the line
+    /// the error is in are parsed on their own. The
+    /// tokens of the failing line are skipped and
+    /// parsing carries on with the next line. If
+    /// a block quote is still open at the end of
+    /// the token stream, only the line opening it
+    /// is skipped and its lines are parsed as if
+    /// they were not quoted. Problems with the envelopes of
+    /// documents and ragged rows of tables are
+    /// recorded as well. The statements
+    /// that could be parsed and the diagnostics are returned
+    /// in an instance of the `RecoveredStatements`
+    /// structure.
+    pub fn parse_recovering(
+        &mut self
+    ) -> RecoveredStatements {
+        let mut statements: Vec<Statement> = Vec::new();
+        let mut errors: Vec<Diagnostic> = Vec::new();
+        if self.source_type == SourceType::Document {
+            let (bodies, envelope_errors) = self.find_documents();
+            errors.extend(envelope_errors);
+            for body in bodies {
+                let tokens: Vec<Token> = self.stream[body].to_vec();
+                if let Ok(mut parser) = Parser::new(&SourceType::Slice, &tokens){
+                    let recovered: RecoveredStatements = parser.parse_recovering();
+                    statements.extend(recovered.statements);
+                    errors.extend(recovered.errors);
+                }
+            }
+            self.cursor = self.stream.len();
+        }
+        while !self.is_done(){
+            let start: usize = self.cursor;
+            let parsed: Result<Statement, JiraiErr> = match self.stream[self.cursor].token_type{
+                TokenType::NewLine => {
+                    self.advance();
+                    continue;
+                },
+                TokenType::Indent if !self.starts_list_item() => {
+                    self.advance();
+                    continue;
+                },
+                TokenType::HeadingMarker => self.parse_heading(),
+                _ => self.parse_block_element(),
+            };
+            match parsed {
+                Ok(statement) => statements.push(statement),
+                Err(error) => {
+                    let diagnostic: Diagnostic = self.diagnose(&error);
+                    let failed: usize = self.failing_line_start(&start);
+                    if self.is_done() && self.stream[start].token_type == TokenType::OpenQuote {
+                        self.cursor = start;
+                        self.diagnostics.clear();
+                    }
+                    else if failed > start {
+                        if let Ok(mut parser) = Parser::new(&SourceType::Slice, &self.stream[start..failed]) &&
+                            let Ok(parsed) = parser.parse(){
+                            statements.extend(parsed);
+                            errors.append(&mut parser.diagnostics);
+                        }
+                        self.cursor = failed;
+                    }
+                    errors.push(diagnostic);
+                    self.synchronize();
+                }
+            };
+            errors.append(&mut self.diagnostics);
+        }
+        RecoveredStatements {
+            statements,
+            errors
+        }
+    }
+
+    /// A function to return the index of the
+    /// first token of the line parsing failed in.
+    /// The line is the one holding the current
+    /// token or, if the current token ends a line or
+    /// the token stream has been consumed, the one
+    /// before it. The search does not go back further
+    /// than the supplied index of the token the
+    /// failed block element started at.
+    pub fn failing_line_start(
+        &self,
+        start: &usize
+    ) -> usize {
+        let end: usize = match self.stream.get(self.cursor){
+            Some(token) if token.token_type != TokenType::NewLine => self.cursor,
+            _ => self.cursor.min(self.stream.len()).saturating_sub(1)
+        };
+        match self.stream[*start..end.max(*start)]
+            .iter()
+            .rposition(|token| token.token_type == TokenType::NewLine){
+            Some(idx) => start + idx + 1,
+            None => *start
+        }
+    }
+
+    /// A function to skip the tokens up to
+    /// and including the next `NewLine` token
+    /// or up to the end of the token stream,
+    /// so that parsing can resume at the start
+    /// of the next line after an error.
+    pub fn synchronize(
+        &mut self
+    ) {
+        while let Some(token) = self.stream.get(self.cursor){
+            let is_new_line: bool = token.token_type == TokenType::NewLine;
+            self.advance();
+            if is_new_line {
+                break;
+            }
+        }
+    }
+
+    /// A function to turn a parse error into
+    /// a diagnostic located at the token the
+    /// cursor currently points to. At the end of
+    /// the token stream, the diagnostic is placed
+    /// at the end of the last token.
+    pub fn diagnose(
+        &self,
+        error: &JiraiErr
+    ) -> Diagnostic {
+        match self.stream.get(self.cursor){
+            Some(token) => token_diagnostic(token, &error.details),
+            None => {
+                let last: &Token = &self.stream[self.stream.len() - 1];
+                Diagnostic::new(
+                    &Span::new(&last.span.end, &last.span.end),
+                    &last.end,
+                    &last.end,
+                    &error.details
+                )
+            }
+        }
+    }
+
+    /// A function to return the location of
+    /// the tokens consumed since the supplied
+    /// index in the token stream. Line breaks
+    /// ending a statement are not part of its
+    /// location.
+    pub fn location_from(
+        &self,
+        start: &usize
+    ) -> Location {
+        let last_idx: usize = self.stream.len() - 1;
+        let first_idx: usize = (*start).min(last_idx);
+        let mut end_idx: usize = self.cursor.saturating_sub(1).clamp(first_idx, last_idx);
+        while end_idx > first_idx && self.stream[end_idx].token_type == TokenType::NewLine {
+            end_idx -= 1;
+        }
+        let first: &Token = &self.stream[first_idx];
+        let last: &Token = &self.stream[end_idx];
+        Location::new(
+            &Span::new(&last.span.end, &first.span.start),
+            &last.end,
+            &first.start
+        )
+    }
+
+    /// A function to locate the documents
+    /// enveloped by `DocumentLimiter` tokens
+    /// in the token stream. Limiters open and
+    /// close documents in turn, so one stream
+    /// can hold several documents. Tokens outside
+    /// the envelopes are ignorable preamble.
+    /// The ranges of the tokens inside each
+    /// envelope are returned together with
+    /// diagnostics for limiters that do not
+    /// stand on a line of their own, for a
+    /// document that is never closed, and for
+    /// a stream without any limiters. In the
+    /// last two cases, the rest of the stream
+    /// is taken as the document's body.
+    pub fn find_documents(
+        &self
+    ) -> (Vec<Range<usize>>, Vec<Diagnostic>) {
+        let mut bodies: Vec<Range<usize>> = Vec::new();
+        let mut errors: Vec<Diagnostic> = Vec::new();
+        let mut opened: Option<usize> = None;
+        for (idx, token) in self.stream.iter().enumerate(){
+            if token.token_type != TokenType::DocumentLimiter {
+                continue;
+            }
+            let line_start: usize = match idx.checked_sub(1).map(|prev| &self.stream[prev]){
+                Some(prev) if prev.token_type == TokenType::Indent => idx - 1,
+                _ => idx
+            };
+            let at_line_start: bool = line_start == 0 ||
+                self.stream[line_start - 1].token_type == TokenType::NewLine;
+            let at_line_end: bool = self.stream
+                .get(idx + 1)
+                .is_none_or(|next| next.token_type == TokenType::NewLine);
+            if !at_line_start || !at_line_end {
+                errors.push(
+                    token_diagnostic(
+                        token,
+                        &format!(
+                            "Misplaced document limiter at position \"{}\"!",
+                            &token.start
+                        )
+                    )
+                );
+            }
+            match opened {
+                Some(opener) => {
+                    bodies.push(self.body_start(&opener)..idx);
+                    opened = None;
+                },
+                None => opened = Some(idx)
+            };
+        }
+        if let Some(opener) = opened {
+            let token: &Token = &self.stream[opener];
+            errors.push(
+                token_diagnostic(
+                    token,
+                    &format!(
+                        "Missing closing document limiter for the document opened at position \"{}\"!",
+                        &token.start
+                    )
+                )
+            );
+            bodies.push(self.body_start(&opener)..self.stream.len());
+        }
+        else if bodies.is_empty(){
+            let token: &Token = &self.stream[0];
+            errors.push(
+                token_diagnostic(
+                    token,
+                    &format!(
+                        "Missing opening document limiter at position \"{}\"!",
+                        &token.start
+                    )
+                )
+            );
+            bodies.push(0..self.stream.len());
+        }
+        (bodies, errors)
+    }
+
+    /// A function to return the index of
+    /// the first token of a document's body,
+    /// given the index of the limiter opening
+    /// it. The line break after the limiter
+    /// is not part of the body.
+    pub fn body_start(
+        &self,
+        opener: &usize
+    ) -> usize {
+        match self.stream.get(opener + 1){
+            Some(token) if token.token_type == TokenType::NewLine => opener + 2,
+            _ => opener + 1
+        }
+    }
+
+    /// A function to parse every document
+    /// enveloped by `DocumentLimiter` tokens in
+    /// the token stream. If the operation is
+    /// successful, a vector holding the statements
+    /// of each document is returned. If a limiter
+    /// is missing or misplaced or a document
+    /// cannot be parsed, an error is returned.
+    pub fn parse_documents(
+        &mut self
+    ) -> Result<Vec<Vec<Statement>>, JiraiErr>{
+        let (bodies, errors) = self.find_documents();
+        if let Some(error) = errors.into_iter().next(){
+            return Err::<Vec<Vec<Statement>>, JiraiErr>(JiraiErr::from(error));
+        }
+        let mut documents: Vec<Vec<Statement>> = Vec::new();
+        for body in bodies {
+            let tokens: Vec<Token> = self.stream[body].to_vec();
+            match Parser::new(&SourceType::Slice, &tokens){
+                Ok(mut parser) => {
+                    documents.push(parser.parse()?);
+                    self.diagnostics.append(&mut parser.diagnostics);
+                },
+                Err(_) => documents.push(Vec::new())
+            };
+        }
+        self.cursor = self.stream.len();
+        Ok(documents)
+    }
+
     /// A function to parse the block element of the
     /// heading. If the operation is successful,
     /// the `Heading` variant of the `Statement`
@@ -253,6 +754,7 @@ impl Parser {
     pub fn parse_heading(
         &mut self
     ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
         let mut level: usize = 0;
         while let Some(token) = self.stream.get(self.cursor){
             if token.token_type == TokenType::HeadingMarker{
@@ -264,14 +766,14 @@ impl Parser {
             }
         }
         if level == 0{
-            return Err::<Statement, JiraiErr>(
+            Err::<Statement, JiraiErr>(
                 JiraiErr::new(
                     &format!(
                         "Expected heading marker at position \"{}\"!", 
-                        &self.stream[self.cursor].end.to_string()
+                        &self.stream[self.cursor].end
                     )
                 )
-            );
+            )
         }
         else { 
             let mut stmt_vec: Vec<InlineStatement> = Vec::new();
@@ -284,7 +786,7 @@ impl Parser {
                     stmt_vec.push(self.parse_inline_statement()?);
                 }
             }
-            Ok(Statement::Heading(level, stmt_vec))
+            Ok(Statement::Heading(self.location_from(&start), level, stmt_vec))
         }
     }
 
@@ -297,52 +799,655 @@ impl Parser {
         &mut self
     ) -> Result<Statement, JiraiErr>{
         let peeked: Token = self.peek()?;
+        let next: Option<TokenType> = self.stream
+            .get(self.cursor + 1)
+            .map(|token| token.token_type.clone());
         match peeked.token_type{
-            TokenType::ListMarker => Ok(self.parse_unordered_list()?),
+            TokenType::Indent |
+            TokenType::ListMarker |
+            TokenType::OrderedListMarker => Ok(self.parse_list()?),
+            TokenType::Divider => Ok(self.parse_divider()?),
+            TokenType::UserString if self.starts_definition_list() => 
+                Ok(self.parse_definition_list()?),
+            TokenType::OpenCurly if self.starts_footnote_definition() => 
+                Ok(self.parse_footnote_definition()?),
+            TokenType::OpenFence => Ok(self.parse_code_block()?),
+            TokenType::TableDelimiter => Ok(self.parse_table()?),
+            TokenType::OpenQuote if self.opens_block_quote() => Ok(self.parse_block_quote()?),
+            TokenType::Comment if next.is_none() || next == Some(TokenType::NewLine) => 
+                Ok(self.parse_comment_block()?),
             _ => Ok(self.parse_paragraph()?)
         }
     }
 
-    /// A function to parse the block element of the
-    /// paragraph. If the operation is successful,
-    /// the `Paragraph` variant of the `Statement`
+    /// A function to parse the block element of
+    /// the block quote. A block quote is opened by
+    /// the `>(` symbol ending a line and closed by
+    /// the `)<` symbol starting a line. The lines in
+    /// between can hold any block elements, including
+    /// other block quotes. Any text following the
+    /// closing symbol on its line is the attribution
+    /// of the quote. If the operation is successful,
+    /// the `BlockQuote` variant of the `Statement`
     /// enumeration is returned. If the operation
     /// fails, an error is returned.
-    pub fn parse_paragraph(
+    pub fn parse_block_quote(
         &mut self
     ) -> Result<Statement, JiraiErr>{
-        let mut stmt_vec: Vec<InlineStatement> = Vec::new();
+        let start: usize = self.cursor;
+        let open_quote: Token = self.expect(&TokenType::OpenQuote)?;
+        let mut children: Vec<Statement> = Vec::new();
+        loop {
+            match self.stream.get(self.cursor).map(|token| token.token_type.clone()){
+                None => return Err::<Statement, JiraiErr>(
+                    JiraiErr::new(
+                        &format!(
+                            "Unclosed block quote opened at position \"{}\"!",
+                            &open_quote.start
+                        )
+                    )
+                ),
+                Some(TokenType::CloseQuote) => break,
+                Some(TokenType::NewLine) => self.advance(),
+                Some(TokenType::UserString) if self.cursor == start + 1 => self.advance(),
+                Some(TokenType::Indent) if !self.starts_list_item() => self.advance(),
+                Some(TokenType::HeadingMarker) => children.push(self.parse_heading()?),
+                Some(_) => children.push(self.parse_block_element()?)
+            };
+        }
+        let _close_quote: Token = self.expect(&TokenType::CloseQuote)?;
+        let mut attribution: Vec<InlineStatement> = Vec::new();
         while let Some(token) = self.stream.get(self.cursor){
-            if token.token_type == TokenType::NewLine{
-                self.advance();
+            if token.token_type == TokenType::NewLine {
                 break;
             }
-            else {
-                stmt_vec.push(self.parse_inline_statement()?);
+            attribution.push(self.parse_inline_statement()?);
+        }
+        let attribution: Option<Vec<InlineStatement>> = Some(attribution)
+            .filter(|attribution| !attribution.is_empty());
+        Ok(Statement::BlockQuote(self.location_from(&start), children, attribution))
+    }
+
+    /// A function to check whether the current
+    /// token opens a block quote, which is the case
+    /// if it is the `>(` symbol followed by nothing
+    /// but whitespace on its line. A boolean
+    /// reflecting this is returned.
+    pub fn opens_block_quote(
+        &self
+    ) -> bool {
+        let first: usize = self.line_content_start();
+        if self.stream.get(first).is_none_or(|token| token.token_type != TokenType::OpenQuote){
+            return false;
+        }
+        let mut idx: usize = first + 1;
+        if self.stream.get(idx).is_some_and(|token| 
+            token.token_type == TokenType::UserString &&
+            token.value.as_deref().is_some_and(|value| value.trim().is_empty())
+        ){
+            idx += 1;
+        }
+        self.stream.get(idx).is_none_or(|token| token.token_type == TokenType::NewLine)
+    }
+
+    /// A function to parse the block element of
+    /// the divider, which marks a break between
+    /// two sections or scenes. If the operation
+    /// is successful, the `Divider` variant of the
+    /// `Statement` enumeration is returned. If the
+    /// operation fails, an error is returned.
+    pub fn parse_divider(
+        &mut self
+    ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
+        let _divider: Token = self.expect(&TokenType::Divider)?;
+        if !self.is_done(){
+            let _new_line: Token = self.expect(&TokenType::NewLine)?;
+        }
+        Ok(Statement::Divider(self.location_from(&start)))
+    }
+
+    /// A function to parse the block element of
+    /// the code block. A code block is opened by
+    /// the `<<<` symbol, optionally followed by an
+    /// info string naming the language of the code,
+    /// and closed by the `>>>` symbol. The lines
+    /// in between are taken verbatim.
+    /// If the operation is successful, the `CodeBlock`
+    /// variant of the `Statement` enumeration is
+    /// returned. If the operation fails, an error
+    /// is returned.
+    pub fn parse_code_block(
+        &mut self
+    ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
+        let open_fence: Token = self.expect(&TokenType::OpenFence)?;
+        let mut code: String = String::new();
+        if self.peek()?.token_type == TokenType::RawString {
+            code = self.expect(&TokenType::RawString)?.value.unwrap_or_default();
+            let _new_line: Token = self.expect(&TokenType::NewLine)?;
+        }
+        if self.peek()?.token_type == TokenType::Indent {
+            self.advance();
+        }
+        let _close_fence: Token = self.expect(&TokenType::CloseFence)?;
+        if !self.is_done(){
+            let _new_line: Token = self.expect(&TokenType::NewLine)?;
+        }
+        Ok(Statement::CodeBlock(self.location_from(&start), open_fence.value, code))
+    }
+
+    /// A function to parse the block element of
+    /// the table. A table is made up of consecutive
+    /// lines starting with the `|` character. The
+    /// first line is the header row and the second
+    /// line holds the alignment of each column. Every
+    /// line after these is a row of the body. Rows
+    /// with fewer cells than the header are padded
+    /// with empty cells and rows with more cells are
+    /// cut short. Each such row is recorded as a
+    /// diagnostic. If the operation is successful,
+    /// the `Table` variant of the `Statement`
+    /// enumeration is returned. If the operation
+    /// fails, an error is returned.
+    pub fn parse_table(
+        &mut self
+    ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
+        let header: TableRow = self.parse_table_row()?;
+        if !self.starts_table_row(){
+            return Err::<Statement, JiraiErr>(
+                JiraiErr::new(
+                    &format!(
+                        "Expected the alignment row of the table at position \"{}\"!",
+                        &header.location.start
+                    )
+                )
+            );
+        }
+        self.cursor = self.line_content_start();
+        let alignment_row: TableRow = self.parse_table_row()?;
+        let mut alignments: Vec<Option<Alignment>> = Vec::new();
+        for cell in &alignment_row.cells {
+            alignments.push(parse_alignment(cell, &alignment_row.location)?);
+        }
+        self.check_row_length(&alignment_row, &header.cells.len());
+        alignments.resize(header.cells.len(), None);
+        let mut rows: Vec<TableRow> = Vec::new();
+        while self.starts_table_row(){
+            self.cursor = self.line_content_start();
+            let mut row: TableRow = self.parse_table_row()?;
+            self.check_row_length(&row, &header.cells.len());
+            row.cells.resize(header.cells.len(), Vec::new());
+            rows.push(row);
+        }
+        Ok(Statement::Table(self.location_from(&start), alignments, header, rows))
+    }
+
+    /// A function to parse a single row
+    /// of a table, which starts with the `|`
+    /// character and holds cells separated by
+    /// the same character. A `|` character at
+    /// the end of the row is optional. If the
+    /// operation is successful, an instance of
+    /// the `TableRow` structure is returned. If
+    /// the operation fails, an error is returned.
+    pub fn parse_table_row(
+        &mut self
+    ) -> Result<TableRow, JiraiErr>{
+        let start: usize = self.cursor;
+        let _delimiter: Token = self.expect(&TokenType::TableDelimiter)?;
+        let mut cells: Vec<Vec<InlineStatement>> = Vec::new();
+        while !self.at_row_end(){
+            let mut cell: Vec<InlineStatement> = Vec::new();
+            while let Some(token) = self.stream.get(self.cursor){
+                if token.token_type == TokenType::NewLine ||
+                    token.token_type == TokenType::TableDelimiter {
+                    break;
+                }
+                cell.push(self.parse_inline_statement()?);
             }
+            cells.push(trim_cell(cell));
+            match self.stream.get(self.cursor){
+                Some(token) if token.token_type == TokenType::TableDelimiter => self.advance(),
+                _ => break
+            };
+        }
+        let location: Location = self.location_from(&start);
+        if !self.is_done(){
+            let _new_line: Token = self.expect(&TokenType::NewLine)?;
+        }
+        Ok(TableRow{ location, cells })
+    }
+
+    /// A function to check whether the cursor
+    /// is at the end of a row of a table. Any
+    /// whitespace before the end of the row is
+    /// consumed. A boolean reflecting this is
+    /// returned.
+    pub fn at_row_end(
+        &mut self
+    ) -> bool {
+        let is_end = |token: Option<&Token>| token
+            .is_none_or(|token| token.token_type == TokenType::NewLine);
+        let current: Option<&Token> = self.stream.get(self.cursor);
+        let is_blank: bool = current.is_some_and(|token| 
+            token.token_type == TokenType::UserString &&
+            token.value.as_deref().is_some_and(|value| value.trim().is_empty())
+        );
+        if is_blank && is_end(self.stream.get(self.cursor + 1)){
+            self.advance();
+        }
+        is_end(self.stream.get(self.cursor))
+    }
+
+    /// A function to check whether the line
+    /// the cursor is at the start of is a row
+    /// of a table. A boolean reflecting this
+    /// is returned.
+    pub fn starts_table_row(
+        &self
+    ) -> bool {
+        self.stream
+            .get(self.line_content_start())
+            .is_some_and(|token| token.token_type == TokenType::TableDelimiter)
+    }
+
+    /// A function to record a diagnostic for
+    /// the supplied row of a table if its number
+    /// of cells differs from the number of cells
+    /// of the header row. Nothing is returned.
+    pub fn check_row_length(
+        &mut self,
+        row: &TableRow,
+        expected: &usize
+    ) {
+        if row.cells.len() != *expected {
+            self.diagnostics.push(
+                Diagnostic::new(
+                    &row.location.span,
+                    &row.location.end,
+                    &row.location.start,
+                    &format!(
+                        "Table row at position \"{}\" has {} cells instead of {}!",
+                        &row.location.start,
+                        &row.cells.len(),
+                        expected
+                    )
+                )
+            );
         }
-        Ok(Statement::Paragraph(stmt_vec))
+    }
+
+    /// A function to parse a comment that
+    /// stands on a line of its own. If the
+    /// operation is successful, the `Comment`
+    /// variant of the `Statement` enumeration
+    /// is returned. If the operation fails,
+    /// an error is returned.
+    pub fn parse_comment_block(
+        &mut self
+    ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
+        let comment: Token = self.expect(&TokenType::Comment)?;
+        if !self.is_done(){
+            let _new_line: Token = self.expect(&TokenType::NewLine)?;
+        }
+        Ok(Statement::Comment(self.location_from(&start), comment.value.unwrap_or_default()))
     }
 
     /// A function to parse the block element of the
-    /// unordered list. If the operation is successful,
-    /// the `UnorderedList` variant of the `Statement`
+    /// paragraph. A paragraph runs over consecutive
+    /// lines, which are joined by soft breaks, and
+    /// ends at a blank line, at the start of another
+    /// block element, or at the end of the token
+    /// stream. If the operation is successful,
+    /// the `Paragraph` variant of the `Statement`
     /// enumeration is returned. If the operation
     /// fails, an error is returned.
-    pub fn parse_unordered_list(
+    pub fn parse_paragraph(
         &mut self
     ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
         let mut stmt_vec: Vec<InlineStatement> = Vec::new();
+        self.parse_inline_lines(&mut stmt_vec, &false)?;
+        Ok(Statement::Paragraph(self.location_from(&start), stmt_vec))
+    }
+
+    /// A function to parse inline statements
+    /// over consecutive lines into the supplied
+    /// vector. The lines are joined by soft breaks.
+    /// Parsing stops at a blank line, at the start
+    /// of another block element, or at the end of
+    /// the token stream. Inside a definition list,
+    /// parsing also stops at the next term or
+    /// definition. If the operation fails, an
+    /// error is returned.
+    pub fn parse_inline_lines(
+        &mut self,
+        stmt_vec: &mut Vec<InlineStatement>,
+        in_definition_list: &bool
+    ) -> Result<(), JiraiErr>{
         while let Some(token) = self.stream.get(self.cursor){
             if token.token_type == TokenType::NewLine{
+                let new_line: usize = self.cursor;
                 self.advance();
-                break;
+                if self.at_block_boundary() || (*in_definition_list && self.definition_marker().is_some()){
+                    break;
+                }
+                self.cursor = self.line_content_start();
+                stmt_vec.push(InlineStatement::SoftBreak(self.location_from(&new_line)));
             }
             else {
                 stmt_vec.push(self.parse_inline_statement()?);
             }
         }
-        Ok(Statement::UnorderedList(stmt_vec))
+        Ok(())
+    }
+
+    /// A function to return the marker of a
+    /// definition list the line the cursor is
+    /// at the start of begins with. The marker is
+    /// either `;` for a term or `:` for a definition,
+    /// followed by whitespace. If the line does not
+    /// start with a marker, `None` is returned.
+    pub fn definition_marker(
+        &self
+    ) -> Option<char> {
+        self.definition_marker_at(&self.cursor)
+    }
+
+    /// A function to return the marker of a
+    /// definition list the line starting at the
+    /// supplied index in the token stream begins
+    /// with. If the line does not start with a
+    /// marker, `None` is returned.
+    pub fn definition_marker_at(
+        &self,
+        idx: &usize
+    ) -> Option<char> {
+        let first: usize = match self.stream.get(*idx){
+            Some(token) if token.token_type == TokenType::Indent => idx + 1,
+            _ => *idx
+        };
+        let token: &Token = self.stream.get(first)?;
+        let value: &str = token.value.as_deref().filter(|_| token.token_type == TokenType::UserString)?;
+        let marker: char = value.chars().next().filter(|marker| *marker == ';' || *marker == ':')?;
+        Some(marker).filter(|_| value[1..].starts_with([' ', '\t']))
+    }
+
+    /// A function to check whether the line the
+    /// cursor is at the start of begins a definition
+    /// list. This is the case if it starts with a
+    /// term and the next line starts with a definition.
+    /// A boolean reflecting this is returned.
+    pub fn starts_definition_list(
+        &self
+    ) -> bool {
+        let next_line: Option<usize> = self.stream[self.cursor.min(self.stream.len())..]
+            .iter()
+            .position(|token| token.token_type == TokenType::NewLine)
+            .map(|idx| self.cursor + idx + 1);
+        self.definition_marker() == Some(';') &&
+            next_line.is_some_and(|next_line| self.definition_marker_at(&next_line) == Some(':'))
+    }
+
+    /// A function to parse the block element of
+    /// the definition list. A definition list is made
+    /// up of consecutive lines starting with a term,
+    /// marked by the `;` character, or a definition,
+    /// marked by the `:` character. Each definition
+    /// belongs to the term above it, so a term can have
+    /// several definitions. Terms and definitions can
+    /// continue onto the following lines. If the
+    /// operation is successful, the `DefinitionList`
+    /// variant of the `Statement` enumeration is
+    /// returned. If the operation fails, an error
+    /// is returned.
+    pub fn parse_definition_list(
+        &mut self
+    ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
+        let mut items: Vec<DefinitionItem> = Vec::new();
+        let mut item_start: usize = start;
+        while let Some(marker) = self.definition_marker(){
+            if marker == ':' && items.is_empty(){
+                break;
+            }
+            self.cursor = self.line_content_start();
+            if marker == ';' {
+                item_start = self.cursor;
+            }
+            let text: Token = self.expect(&TokenType::UserString)?;
+            let mut contents: Vec<InlineStatement> = strip_marker(&text).into_iter().collect();
+            self.parse_inline_lines(&mut contents, &true)?;
+            let location: Location = self.location_from(&item_start);
+            match items.last_mut(){
+                Some(item) if marker == ':' => {
+                    item.location = location;
+                    item.definitions.push(contents);
+                },
+                _ => items.push(
+                    DefinitionItem{
+                        location,
+                        term: contents,
+                        definitions: Vec::new()
+                    }
+                )
+            };
+        }
+        Ok(Statement::DefinitionList(self.location_from(&start), items))
+    }
+
+    /// A function to check whether the line
+    /// the cursor is at the start of defines a
+    /// footnote. This is the case if it starts with
+    /// a footnote reference, like `{*[note]}`, that
+    /// is directly followed by a colon. A boolean
+    /// reflecting this is returned.
+    pub fn starts_footnote_definition(
+        &self
+    ) -> bool {
+        let first: usize = self.line_content_start();
+        let expected: [TokenType; 6] = [
+            TokenType::OpenCurly,
+            TokenType::BoldText,
+            TokenType::OpenSquare,
+            TokenType::UserString,
+            TokenType::CloseSquare,
+            TokenType::CloseCurly
+        ];
+        let matches_reference: bool = expected
+            .iter()
+            .enumerate()
+            .all(|(idx, token_type)| self.stream
+                .get(first + idx)
+                .is_some_and(|token| token.token_type == *token_type)
+            );
+        matches_reference && self.stream.get(first + expected.len()).is_some_and(|token| 
+            token.token_type == TokenType::UserString &&
+            token.value.as_deref().is_some_and(|value| value.starts_with(':'))
+        )
+    }
+
+    /// A function to parse the block element of
+    /// a footnote definition. A definition starts
+    /// with a footnote reference followed by a colon,
+    /// like `{*[note]}:`, and the text of the footnote
+    /// runs over the following lines like a paragraph.
+    /// If the operation is successful, the
+    /// `FootnoteDefinition` variant of the `Statement`
+    /// enumeration is returned. If the operation fails,
+    /// an error is returned.
+    pub fn parse_footnote_definition(
+        &mut self
+    ) -> Result<Statement, JiraiErr>{
+        let start: usize = self.cursor;
+        let _open_curly: Token = self.expect(&TokenType::OpenCurly)?;
+        let _marker: Token = self.expect(&TokenType::BoldText)?;
+        let id: String = self.parse_bracketed_text()?;
+        let _close_curly: Token = self.expect(&TokenType::CloseCurly)?;
+        let text: Token = self.expect(&TokenType::UserString)?;
+        let mut stmt_vec: Vec<InlineStatement> = strip_marker(&text).into_iter().collect();
+        self.parse_inline_lines(&mut stmt_vec, &false)?;
+        Ok(Statement::FootnoteDefinition(self.location_from(&start), id, stmt_vec))
+    }
+
+    /// A function to check whether the current
+    /// token ends a paragraph when it starts a
+    /// line. This is the case for the end of the
+    /// token stream, a blank line, the markers of
+    /// other block elements, a document limiter,
+    /// and a comment standing on a line of its
+    /// own. The indentation of the line is looked
+    /// past. A boolean reflecting this is returned.
+    pub fn at_block_boundary(
+        &self
+    ) -> bool {
+        let first: usize = self.line_content_start();
+        let next: Option<&TokenType> = self.stream
+            .get(first + 1)
+            .map(|token| &token.token_type);
+        match self.stream.get(first).map(|token| &token.token_type){
+            None => true,
+            Some(TokenType::NewLine) => true,
+            Some(TokenType::ListMarker) => true,
+            Some(TokenType::OrderedListMarker) => true,
+            Some(TokenType::HeadingMarker) => true,
+            Some(TokenType::Divider) => true,
+            Some(TokenType::OpenFence) => true,
+            Some(TokenType::TableDelimiter) => true,
+            Some(TokenType::CloseQuote) => true,
+            Some(TokenType::DocumentLimiter) => true,
+            Some(TokenType::OpenQuote) => self.opens_block_quote(),
+            Some(TokenType::Comment) => next.is_none_or(|next| *next == TokenType::NewLine),
+            Some(TokenType::OpenCurly) => self.starts_footnote_definition(),
+            Some(TokenType::UserString) => self.starts_definition_list(),
+            Some(_) => false
+        }
+    }
+
+    /// A function to parse the block element of a
+    /// list. Consecutive lines starting with the same
+    /// kind of list marker are collected into one list.
+    /// Items indented further than the first item of the
+    /// list form a nested list inside the preceding item.
+    /// An ordered list starts at the number of its first
+    /// item, or at one if that item is numbered automatically.
+    /// If the operation is successful, the `UnorderedList`
+    /// or the `OrderedList` variant of the `Statement`
+    /// enumeration is returned. If the operation fails,
+    /// an error is returned.
+    pub fn parse_list(
+        &mut self
+    ) -> Result<Statement, JiraiErr>{
+        self.parse_list_within(&None)
+    }
+
+    /// A function to parse a list nested inside
+    /// a list whose first item has the supplied
+    /// indentation. Items indented further than the
+    /// first item of the list form a nested list
+    /// inside the preceding item. Items indented
+    /// no further than the enclosing list end the
+    /// list, and any other item belongs to the list.
+    /// A list that is not nested has no enclosing
+    /// indentation. If the operation is successful,
+    /// the `UnorderedList` or the `OrderedList` variant
+    /// of the `Statement` enumeration is returned. If
+    /// the operation fails, an error is returned.
+    pub fn parse_list_within(
+        &mut self,
+        enclosing: &Option<usize>
+    ) -> Result<Statement, JiraiErr>{
+        let level: usize = self.line_indentation();
+        let start: usize = self.line_content_start();
+        let marker: Token = self.stream[start].clone();
+        let mut items: Vec<ListItem> = Vec::new();
+        while self.starts_list_item(){
+            let indentation: usize = self.line_indentation();
+            let same_kind: bool = self.stream[self.line_content_start()].token_type == marker.token_type;
+            if enclosing.is_some_and(|enclosing| indentation <= enclosing) ||
+                (indentation <= level && !same_kind) {
+                break;
+            }
+            match items.last_mut(){
+                Some(item) if indentation > level => {
+                    let nested: Statement = self.parse_list_within(&Some(level))?;
+                    item.children.push(nested);
+                },
+                _ => {
+                    self.cursor = self.line_content_start();
+                    items.push(self.parse_list_item()?);
+                }
+            };
+        }
+        let location: Location = self.location_from(&start);
+        if marker.token_type == TokenType::OrderedListMarker {
+            let number: String = marker.value.unwrap_or_default();
+            let first: usize = if number == "#" {
+                1
+            }
+            else {
+                match number.parse::<usize>(){
+                    Ok(first) => first,
+                    Err(_) => return Err::<Statement, JiraiErr>(
+                        JiraiErr::new(
+                            &format!(
+                                "Invalid list number at position \"{}\"!",
+                                &marker.start
+                            )
+                        )
+                    )
+                }
+            };
+            Ok(Statement::OrderedList(location, first, items))
+        }
+        else {
+            Ok(Statement::UnorderedList(location, items))
+        }
+    }
+
+    /// A function to check whether the current
+    /// token starts an item of a list, possibly
+    /// after the indentation of the line. A boolean
+    /// reflecting this is returned.
+    pub fn starts_list_item(
+        &self
+    ) -> bool {
+        self.stream
+            .get(self.line_content_start())
+            .is_some_and(|token| 
+                token.token_type == TokenType::ListMarker ||
+                token.token_type == TokenType::OrderedListMarker
+            )
+    }
+
+    /// A function to return the index of the
+    /// first token after the indentation of the
+    /// line the cursor is at the start of. If the
+    /// line is not indented, the cursor is returned.
+    pub fn line_content_start(
+        &self
+    ) -> usize {
+        match self.stream.get(self.cursor){
+            Some(token) if token.token_type == TokenType::Indent => self.cursor + 1,
+            _ => self.cursor
+        }
+    }
+
+    /// A function to return the width of the
+    /// indentation of the line the cursor is at
+    /// the start of. A line without indentation
+    /// has a width of zero.
+    pub fn line_indentation(
+        &self
+    ) -> usize {
+        match self.stream.get(self.cursor){
+            Some(token) if token.token_type == TokenType::Indent => 
+                indentation_width(token.value.as_deref().unwrap_or_default()),
+            _ => 0
+        }
     }
 
     /// A function to parse an inline block element.
@@ -355,41 +1460,190 @@ impl Parser {
     ) -> Result<InlineStatement, JiraiErr>{
         let peeked: Token = self.peek()?;
         match peeked.token_type{
+            TokenType::Comment => Ok(self.parse_comment()?),
             TokenType::BoldText => Ok(self.parse_bold_text()?),
-            TokenType::ListMarker => Ok(self.parse_list_item()?),
             TokenType::OpenAngle => Ok(self.parse_inline_code()?),
             TokenType::OpenCurly => Ok(self.parse_linked_item()?),
             TokenType::ItalicText => Ok(self.parse_italic_text()?),
-            TokenType::CloseAngle => Ok(self.parse_block_quote()?),
+            TokenType::HighlightText => Ok(self.parse_highlight_text()?),
+            TokenType::UnderlineText => Ok(self.parse_underline_text()?),
+            TokenType::StrikethroughText => Ok(self.parse_strikethrough_text()?),
+            TokenType::OpenQuote => Ok(self.parse_quote()?),
+            TokenType::DocumentLimiter => Err::<InlineStatement, JiraiErr>(
+                JiraiErr::new(
+                    &format!(
+                        "Unexpected document limiter at position \"{}\"!",
+                        &peeked.start
+                    )
+                )
+            ),
+            TokenType::ListMarker => Err::<InlineStatement, JiraiErr>(
+                JiraiErr::new(
+                    &format!(
+                        "Unexpected list marker at position \"{}\"!",
+                        &peeked.start
+                    )
+                )
+            ),
+            TokenType::TableDelimiter => Err::<InlineStatement, JiraiErr>(
+                JiraiErr::new(
+                    &format!(
+                        "Unexpected table delimiter at position \"{}\"!",
+                        &peeked.start
+                    )
+                )
+            ),
             _ => Ok(self.parse_text()?)
         }
     }
 
+    /// A function to parse the checkbox of a
+    /// task item after its list marker. A checkbox
+    /// is either `[ ]` for an open task or `[x]` for
+    /// a task that is done, optionally preceded by
+    /// whitespace. If a checkbox is found, it is
+    /// consumed and whether the task is done is
+    /// returned. Otherwise, the cursor is left
+    /// untouched and `None` is returned.
+    pub fn parse_task_box(
+        &mut self
+    ) -> Option<bool> {
+        let idx: usize = self.task_box_start();
+        let tokens: &[Token] = self.stream.get(idx..idx + 3)?;
+        if tokens[0].token_type != TokenType::OpenSquare ||
+            tokens[1].token_type != TokenType::UserString ||
+            tokens[2].token_type != TokenType::CloseSquare {
+            return None;
+        }
+        let done: bool = match tokens[1].value.as_deref(){
+            Some(" ") => false,
+            Some("x") | Some("X") => true,
+            _ => return None
+        };
+        self.cursor = idx + 3;
+        Some(done)
+    }
+
+    /// A function to return the index of the token
+    /// a checkbox would start at, skipping the
+    /// whitespace after the list marker.
+    pub fn task_box_start(
+        &self
+    ) -> usize {
+        let is_blank: bool = self.stream.get(self.cursor).is_some_and(|token| 
+            token.token_type == TokenType::UserString &&
+            token.value.as_deref().is_some_and(|value| value.trim().is_empty())
+        );
+        self.cursor + usize::from(is_blank)
+    }
+
+    /// A function to parse text in square brackets
+    /// at the start of a list item that is not a
+    /// checkbox, as in `[y]`, into the supplied vector.
+    /// The brackets and the text between them are kept
+    /// as plain text. If the item does not start with
+    /// square brackets, the cursor is left untouched.
+    /// If the operation fails, an error is returned.
+    pub fn parse_box_text(
+        &mut self,
+        stmt_vec: &mut Vec<InlineStatement>
+    ) -> Result<(), JiraiErr>{
+        let idx: usize = self.task_box_start();
+        let types: Vec<&TokenType> = self.stream
+            .iter()
+            .skip(idx)
+            .take(3)
+            .map(|token| &token.token_type)
+            .collect::<Vec<&TokenType>>();
+        let length: usize = match types.as_slice(){
+            [TokenType::OpenSquare, TokenType::CloseSquare, ..] => 2,
+            [TokenType::OpenSquare, TokenType::UserString, TokenType::CloseSquare] => 3,
+            _ => return Ok(())
+        };
+        if idx > self.cursor {
+            stmt_vec.push(self.parse_text()?);
+        }
+        let text: String = self.stream[idx..idx + length]
+            .iter()
+            .map(|token| match token.token_type {
+                TokenType::OpenSquare => "[",
+                TokenType::CloseSquare => "]",
+                _ => token.value.as_deref().unwrap_or_default()
+            })
+            .collect::<String>();
+        self.cursor = idx + length;
+        stmt_vec.push(InlineStatement::Text(self.location_from(&idx), text));
+        Ok(())
+    }
+
     /// A function to parse inline markup for
-    /// links or images. If the operation is
-    /// successful either the `Image` or `Link`
-    /// variant of the `InlineStatement` enumeration
-    /// is returned. If the operation fails, an error
+    /// links, images, footnote references, or the
+    /// text elements for superscript, subscript,
+    /// keyboard input, and sample output. These
+    /// are marked by `^`, `_`, `+`, and `=`, as in
+    /// `{^[st]}`. If the operation is successful,
+    /// the matching variant of the `InlineStatement`
+    /// enumeration is returned. If the operation fails, an error
     /// is returned.
     pub fn parse_linked_item(
         &mut self
     ) -> Result<InlineStatement, JiraiErr>{
+        let start: usize = self.cursor;
         let _open_curly: Token = self.expect(&TokenType::OpenCurly)?;
         let peeked: Token = self.peek()?;
         match peeked.token_type{
-            TokenType::ImageMarker => Ok(self.parse_image_item()?),
-            TokenType::LinkMarker => Ok(self.parse_link_item()?),
+            TokenType::ImageMarker => {
+                let image: Image = self.parse_image_item()?;
+                Ok(InlineStatement::Image(self.location_from(&start), image))
+            },
+            TokenType::BoldText => {
+                let _marker: Token = self.expect(&TokenType::BoldText)?;
+                let id: String = self.parse_bracketed_text()?;
+                let _close_curly: Token = self.expect(&TokenType::CloseCurly)?;
+                Ok(InlineStatement::FootnoteReference(self.location_from(&start), id))
+            },
+            TokenType::UserString if peeked.value.as_deref().is_some_and(|marker| 
+                ["^", "_", "+", "="].contains(&marker)
+            ) => {
+                let marker: Token = self.expect(&TokenType::UserString)?;
+                let text: String = self.parse_bracketed_text()?;
+                let _close_curly: Token = self.expect(&TokenType::CloseCurly)?;
+                let location: Location = self.location_from(&start);
+                match marker.value.as_deref(){
+                    Some("^") => Ok(InlineStatement::Superscript(location, text)),
+                    Some("_") => Ok(InlineStatement::Subscript(location, text)),
+                    Some("+") => Ok(InlineStatement::Keyboard(location, split_keys(&text))),
+                    _ => Ok(InlineStatement::Sample(location, text))
+                }
+            },
+            TokenType::LinkMarker => {
+                let link: Link = self.parse_link_item()?;
+                Ok(InlineStatement::Link(self.location_from(&start), link))
+            },
             _ => Err::<InlineStatement, JiraiErr>(
                 JiraiErr::new(
                     &format!(
-                        "Expected a link or image marker at position \"{}\"!", 
-                        &peeked.start.to_string()
+                        "Expected a link, image, footnote, or text element marker at position \"{}\"!", 
+                        &peeked.start
                     )
                 )
             )
         }
     }
 
+    /// A function to parse a comment inside
+    /// inline markup. If the operation is successful
+    /// the `Comment` variant of the `InlineStatement`
+    /// enumeration is returned. If the operation fails,
+    /// an error is returned.
+    pub fn parse_comment(
+        &mut self
+    ) -> Result<InlineStatement, JiraiErr>{
+        let start: usize = self.cursor;
+        let comment: Token = self.expect(&TokenType::Comment)?;
+        Ok(InlineStatement::Comment(self.location_from(&start), comment.value.unwrap_or_default()))
+    }
+
     /// A function to parse inline markup for
     /// bold text. If the operation is successful the
     /// `BoldText` variant of the `InlineStatement` 
@@ -398,19 +1652,26 @@ impl Parser {
     pub fn parse_bold_text(
         &mut self
     ) -> Result<InlineStatement, JiraiErr>{
-        let _open_bold: Token = self.expect(&TokenType::BoldText)?;
+        let start: usize = self.cursor;
+        let open_bold: Token = self.expect(&TokenType::BoldText)?;
         let mut contents: Vec<InlineStatement> = Vec::new();
         loop {
-            let next: Token = self.peek()?;
-            if next.token_type == TokenType::BoldText{
-                break;
-            }
-            else {
-                contents.push(self.parse_inline_statement()?);
-            }
+            match self.stream.get(self.cursor).map(|token| token.token_type.clone()){
+                None => return Err::<InlineStatement, JiraiErr>(
+                    JiraiErr::new(
+                        &format!(
+                            "Unclosed bold text opened at position \"{}\"!",
+                            &open_bold.start
+                        )
+                    )
+                ),
+                Some(TokenType::BoldText) => break,
+                Some(TokenType::NewLine) => contents.push(self.parse_styled_break(&open_bold, "bold")?),
+                Some(_) => contents.push(self.parse_inline_statement()?)
+            };
         }
         let _close_bold: Token = self.expect(&TokenType::BoldText)?;
-        Ok(InlineStatement::BoldText(Box::new(contents)))
+        Ok(InlineStatement::BoldText(self.location_from(&start), Box::new(contents)))
     }
 
     /// A function to parse inline markup for
@@ -421,41 +1682,194 @@ impl Parser {
     pub fn parse_italic_text(
         &mut self
     ) -> Result<InlineStatement, JiraiErr>{
-        let _open_italic: Token = self.expect(&TokenType::ItalicText)?;
+        let start: usize = self.cursor;
+        let open_italic: Token = self.expect(&TokenType::ItalicText)?;
         let mut contents: Vec<InlineStatement> = Vec::new();
         loop {
-            let next: Token = self.peek()?;
-            if next.token_type == TokenType::ItalicText{
-                break;
-            }
-            else {
-                contents.push(self.parse_inline_statement()?);
-            }
+            match self.stream.get(self.cursor).map(|token| token.token_type.clone()){
+                None => return Err::<InlineStatement, JiraiErr>(
+                    JiraiErr::new(
+                        &format!(
+                            "Unclosed italic text opened at position \"{}\"!",
+                            &open_italic.start
+                        )
+                    )
+                ),
+                Some(TokenType::ItalicText) => break,
+                Some(TokenType::NewLine) => contents.push(self.parse_styled_break(&open_italic, "italic")?),
+                Some(_) => contents.push(self.parse_inline_statement()?)
+            };
         }
         let _close_italic: Token = self.expect(&TokenType::ItalicText)?;
-        Ok(InlineStatement::ItalicText(Box::new(contents)))
+        Ok(InlineStatement::ItalicText(self.location_from(&start), Box::new(contents)))
+    }
+
+    /// A function to parse the line break at the
+    /// current `NewLine` token inside styled text
+    /// opened by the supplied token. If the next line
+    /// continues the block element, the cursor is moved
+    /// to its first token and the `SoftBreak` variant
+    /// of the `InlineStatement` enumeration is returned.
+    /// If the next line is blank or starts another block
+    /// element, the cursor is left at the line break and
+    /// an error naming the supplied kind of text is
+    /// returned.
+    pub fn parse_styled_break(
+        &mut self,
+        open_style: &Token,
+        kind: &str
+    ) -> Result<InlineStatement, JiraiErr>{
+        let new_line: usize = self.cursor;
+        self.advance();
+        if self.at_block_boundary(){
+            self.cursor = new_line;
+            return Err::<InlineStatement, JiraiErr>(
+                JiraiErr::new(
+                    &format!(
+                        "Unclosed {} text opened at position \"{}\"!",
+                        kind,
+                        &open_style.start
+                    )
+                )
+            );
+        }
+        self.cursor = self.line_content_start();
+        Ok(InlineStatement::SoftBreak(self.location_from(&new_line)))
     }
 
     /// A function to parse inline markup for
-    /// a list item. If the operation is successful the
-    /// `ListItem` variant of the `InlineStatement` 
+    /// struck-through text. If the operation is successful the
+    /// `StrikethroughText` variant of the `InlineStatement` 
     /// enumeration is returned. If the operation fails, 
     /// an error is returned.
-    pub fn parse_list_item(
+    pub fn parse_strikethrough_text(
         &mut self
     ) -> Result<InlineStatement, JiraiErr>{
-        let _list_marker: Token = self.expect(&TokenType::ListMarker)?;
+        let start: usize = self.cursor;
+        let open_strikethrough: Token = self.expect(&TokenType::StrikethroughText)?;
         let mut contents: Vec<InlineStatement> = Vec::new();
         loop {
-            let next: Token = self.peek()?;
-            if next.token_type == TokenType::NewLine || self.is_done(){
-                break;
+            match self.stream.get(self.cursor).map(|token| token.token_type.clone()){
+                None => return Err::<InlineStatement, JiraiErr>(
+                    JiraiErr::new(
+                        &format!(
+                            "Unclosed struck-through text opened at position \"{}\"!",
+                            &open_strikethrough.start
+                        )
+                    )
+                ),
+                Some(TokenType::NewLine) => contents.push(self.parse_styled_break(&open_strikethrough, "struck-through")?),
+                Some(TokenType::StrikethroughText) => break,
+                Some(_) => contents.push(self.parse_inline_statement()?)
+            };
+        }
+        let _close_strikethrough: Token = self.expect(&TokenType::StrikethroughText)?;
+        Ok(InlineStatement::StrikethroughText(self.location_from(&start), Box::new(contents)))
+    }
+
+    /// A function to parse inline markup for
+    /// underlined text. If the operation is successful the
+    /// `UnderlineText` variant of the `InlineStatement` 
+    /// enumeration is returned. If the operation fails, 
+    /// an error is returned.
+    pub fn parse_underline_text(
+        &mut self
+    ) -> Result<InlineStatement, JiraiErr>{
+        let start: usize = self.cursor;
+        let open_underline: Token = self.expect(&TokenType::UnderlineText)?;
+        let mut contents: Vec<InlineStatement> = Vec::new();
+        loop {
+            match self.stream.get(self.cursor).map(|token| token.token_type.clone()){
+                None => return Err::<InlineStatement, JiraiErr>(
+                    JiraiErr::new(
+                        &format!(
+                            "Unclosed underlined text opened at position \"{}\"!",
+                            &open_underline.start
+                        )
+                    )
+                ),
+                Some(TokenType::NewLine) => contents.push(self.parse_styled_break(&open_underline, "underlined")?),
+                Some(TokenType::UnderlineText) => break,
+                Some(_) => contents.push(self.parse_inline_statement()?)
+            };
+        }
+        let _close_underline: Token = self.expect(&TokenType::UnderlineText)?;
+        Ok(InlineStatement::UnderlineText(self.location_from(&start), Box::new(contents)))
+    }
+
+    /// A function to parse inline markup for
+    /// highlighted text. If the operation is successful the
+    /// `HighlightText` variant of the `InlineStatement` 
+    /// enumeration is returned. If the operation fails, 
+    /// an error is returned.
+    pub fn parse_highlight_text(
+        &mut self
+    ) -> Result<InlineStatement, JiraiErr>{
+        let start: usize = self.cursor;
+        let open_highlight: Token = self.expect(&TokenType::HighlightText)?;
+        let mut contents: Vec<InlineStatement> = Vec::new();
+        loop {
+            match self.stream.get(self.cursor).map(|token| token.token_type.clone()){
+                None => return Err::<InlineStatement, JiraiErr>(
+                    JiraiErr::new(
+                        &format!(
+                            "Unclosed highlighted text opened at position \"{}\"!",
+                            &open_highlight.start
+                        )
+                    )
+                ),
+                Some(TokenType::NewLine) => contents.push(self.parse_styled_break(&open_highlight, "highlighted")?),
+                Some(TokenType::HighlightText) => break,
+                Some(_) => contents.push(self.parse_inline_statement()?)
+            };
+        }
+        let _close_highlight: Token = self.expect(&TokenType::HighlightText)?;
+        Ok(InlineStatement::HighlightText(self.location_from(&start), Box::new(contents)))
+    }
+
+    /// A function to parse an item of a list.
+    /// An item runs until the end of its line
+    /// and continues onto the following lines
+    /// unless they are blank or start another
+    /// block element or item. If the operation
+    /// is successful, an instance of the `ListItem`
+    /// structure is returned. If the operation
+    /// fails, an error is returned.
+    pub fn parse_list_item(
+        &mut self
+    ) -> Result<ListItem, JiraiErr>{
+        let start: usize = self.cursor;
+        if !self.starts_list_item(){
+            return Err::<ListItem, JiraiErr>(
+                JiraiErr::new(
+                    &format!(
+                        "Expected a list marker at position \"{}\"!",
+                        &self.peek()?.start
+                    )
+                )
+            );
+        }
+        self.advance();
+        let task: Option<bool> = self.parse_task_box();
+        let mut contents: Vec<InlineStatement> = Vec::new();
+        if task.is_none(){
+            self.parse_box_text(&mut contents)?;
+        }
+        while let Some(token) = self.stream.get(self.cursor){
+            if token.token_type == TokenType::NewLine{
+                let new_line: usize = self.cursor;
+                self.advance();
+                if self.at_block_boundary(){
+                    break;
+                }
+                self.cursor = self.line_content_start();
+                contents.push(InlineStatement::SoftBreak(self.location_from(&new_line)));
             }
             else {
                 contents.push(self.parse_inline_statement()?);
             }
         }
-        Ok(InlineStatement::ListItem(Box::new(contents)))
+        Ok(ListItem{ location: self.location_from(&start), task, contents, children: Vec::new() })
     }
 
     /// A function to parse inline markup for
@@ -466,8 +1880,9 @@ impl Parser {
     pub fn parse_inline_code(
         &mut self
     ) -> Result<InlineStatement, JiraiErr>{
+        let start: usize = self.cursor;
         let open_angle: Token = self.expect(&TokenType::OpenAngle)?;
-        let code_text: Token = self.expect(&TokenType::UserString)?;
+        let code_text: Token = self.expect(&TokenType::RawString)?;
         let _close_angle: Token = self.expect(&TokenType::CloseAngle)?;
         let text_str: String = match code_text.value {
             Some(text_str) => text_str,
@@ -475,94 +1890,96 @@ impl Parser {
                 JiraiErr::new(
                     &format!(
                         "Expected text at positon \"{}\"!",
-                        open_angle.end.to_string()
+                        open_angle.end
                     )
                 )
             )
         };
-        Ok(InlineStatement::Code(text_str))
+        Ok(InlineStatement::Code(self.location_from(&start), text_str))
     }
 
     /// A function to parse inline markup for an
-    /// inline block quote. If the operation is successful, 
-    /// the `BlockQuote` variant of the `InlineStatement` 
+    /// inline quote. If the operation is successful, 
+    /// the `Quote` variant of the `InlineStatement` 
     /// enumeration is returned. If the operation fails, 
     /// an error is returned.
-    pub fn parse_block_quote(
+    pub fn parse_quote(
         &mut self
     ) -> Result<InlineStatement, JiraiErr>{
-        let _close_angle_bracket: Token = self.expect(&TokenType::CloseAngle)?;
-        let open_bracket: Token = self.expect(&TokenType::OpenBracket)?;
-        let quote: Token = self.expect(&TokenType::UserString)?;
-        let _close_bracket: Token = self.expect(&TokenType::CloseBracket)?;
-        let _open_angle_bracket: Token = self.expect(&TokenType::OpenAngle)?;
+        let start: usize = self.cursor;
+        let open_quote: Token = self.expect(&TokenType::OpenQuote)?;
+        let quote: Token = self.expect(&TokenType::RawString)?;
+        let _close_quote: Token = self.expect(&TokenType::CloseQuote)?;
         let quote_text: String = match quote.value{
             Some(quote_text) => quote_text,
             None => return Err::<InlineStatement, JiraiErr>(
                 JiraiErr::new(
                     &format!(
                         "Expected text at positon \"{}\"!",
-                        open_bracket.end.to_string()
+                        open_quote.end
                     )
                 )
             )
         };
-        Ok(InlineStatement::BlockQuote(quote_text))
+        Ok(InlineStatement::Quote(self.location_from(&start), quote_text))
     }
 
 
     /// A function to parse inline markup for an
-    /// inline link. If the operation is successful the
-    /// `Link` variant of the `InlineStatement` 
-    /// enumeration is returned. If the operation fails, 
-    /// an error is returned.
+    /// inline link after the opening curly brace.
+    /// The text for the `alt` attribute is optional,
+    /// so a link either has three strings enclosed
+    /// by square brackets or only the link text and
+    /// the URL. If the operation is successful, an
+    /// instance of the `Link` structure is returned.
+    /// If the operation fails, an error is returned.
     pub fn parse_link_item(
         &mut self
-    ) -> Result<InlineStatement, JiraiErr>{
+    ) -> Result<Link, JiraiErr>{
         let _link_marker: Token = self.expect(&TokenType::LinkMarker)?;
-        let _alt_open_square: Token = self.expect(&TokenType::OpenSquare)?;
-        let alt_text: Token = self.expect(&TokenType::UserString)?;
-        let _alt_close_square: Token = self.expect(&TokenType::CloseSquare)?;
-        let link_text_open_square: Token = self.expect(&TokenType::OpenSquare)?;
-        let link_text: Token = self.expect(&TokenType::UserString)?;
-        let _link_text_close_square: Token = self.expect(&TokenType::CloseSquare)?;
-        let link_open_square: Token = self.expect(&TokenType::OpenSquare)?;
-        let link_url_text: Token = self.expect(&TokenType::UserString)?;
-        let _link_close_square: Token = self.expect(&TokenType::CloseSquare)?;
-        let _link_close_curly: Token = self.expect(&TokenType::CloseCurly)?;
-        let link_str: String = match link_text.value {
-            Some(link_str) => link_str,
-            None => return Err::<InlineStatement, JiraiErr>(
-                JiraiErr::new(
-                    &format!(
-                        "Expected text at positon \"{}\"!",
-                        link_text_open_square.end.to_string()
-                    )
-                )
-            )
+        let first: String = self.parse_bracketed_text()?;
+        let second: String = self.parse_bracketed_text()?;
+        let link: Link = if self.peek()?.token_type == TokenType::OpenSquare {
+            Link{ alt: Some(first), link_text: second, url: self.parse_bracketed_text()? }
+        }
+        else {
+            Link{ alt: None, link_text: first, url: second }
         };
-        let url_str: String = match link_url_text.value {
-            Some(url_str) => url_str,
-            None => return Err::<InlineStatement, JiraiErr>(
+        let _link_close_curly: Token = self.expect(&TokenType::CloseCurly)?;
+        Ok(link)
+    }
+
+    /// A function to parse a string enclosed
+    /// by square brackets. If the operation is
+    /// successful, the string is returned. If the
+    /// operation fails, an error is returned.
+    pub fn parse_bracketed_text(
+        &mut self
+    ) -> Result<String, JiraiErr>{
+        let open_square: Token = self.expect(&TokenType::OpenSquare)?;
+        let text: Token = self.expect(&TokenType::UserString)?;
+        let _close_square: Token = self.expect(&TokenType::CloseSquare)?;
+        match text.value {
+            Some(text_str) => Ok(text_str),
+            None => Err::<String, JiraiErr>(
                 JiraiErr::new(
                     &format!(
                         "Expected text at positon \"{}\"!",
-                        link_open_square.end.to_string()
+                        open_square.end
                     )
                 )
             )
-        };
-        Ok(InlineStatement::Link(Link{ alt: alt_text.value, url: url_str, link_text: link_str }))
+        }
     }
 
     /// A function to parse inline markup for an
-    /// inline image. If the operation is successful the
-    /// `Image` variant of the `InlineStatement` 
-    /// enumeration is returned. If the operation fails, 
-    /// an error is returned.
+    /// inline image after the opening curly brace.
+    /// If the operation is successful, an instance
+    /// of the `Image` structure is returned. If the
+    /// operation fails, an error is returned.
     pub fn parse_image_item(
         &mut self
-    ) -> Result<InlineStatement, JiraiErr>{
+    ) -> Result<Image, JiraiErr>{
         let _image_marker: Token = self.expect(&TokenType::ImageMarker)?;
         let _alt_open_square: Token = self.expect(&TokenType::OpenSquare)?;
         let alt_text: Token = self.expect(&TokenType::UserString)?;
@@ -573,16 +1990,16 @@ impl Parser {
         let _image_close_curly: Token = self.expect(&TokenType::CloseCurly)?;
         let url_str: String = match image_text.value {
             Some(url_str) => url_str,
-            None => return Err::<InlineStatement, JiraiErr>(
+            None => return Err::<Image, JiraiErr>(
                 JiraiErr::new(
                     &format!(
                         "Expected text at positon \"{}\"!",
-                        image_open_square.end.to_string()
+                        image_open_square.end
                     )
                 )
             )
         };
-        Ok(InlineStatement::Image(Image{ alt: alt_text.value, url: url_str }))
+        Ok(Image{ alt: alt_text.value, url: url_str })
     }
 
     /// A function to parse inline markup for
@@ -593,6 +2010,7 @@ impl Parser {
     pub fn parse_text(
         &mut self
     ) -> Result<InlineStatement, JiraiErr>{
+        let start: usize = self.cursor;
         let token: Token = self.expect(&TokenType::UserString)?;
         let text_str: String = match token.value {
             Some(text_str) => text_str,
@@ -600,11 +2018,259 @@ impl Parser {
                 JiraiErr::new(
                     &format!(
                         "Expected text at positon \"{}\"!",
-                        token.start.to_string()
+                        token.start
                     )
                 )
             )
         };
-        Ok(InlineStatement::Text(text_str))
+        Ok(InlineStatement::Text(self.location_from(&start), text_str))
+    }
+}
+
+
+/// A function to create a diagnostic
+/// with the supplied message that is
+/// located at the supplied token.
+pub fn token_diagnostic(
+    token: &Token,
+    message: &str
+) -> Diagnostic {
+    Diagnostic::new(
+        &token.span,
+        &token.end,
+        &token.start,
+        message
+    )
+}
+
+/// A function to split the supplied key
+/// combination, like `Ctrl+Shift+P`, into its
+/// keys and return them. A doubled `+` character
+/// stands for the `+` key itself, as in `Ctrl++`.
+pub fn split_keys(
+    combination: &str
+) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    let mut pending_plus: bool = false;
+    for key in combination.split('+'){
+        if !key.trim().is_empty(){
+            keys.push(key.trim().to_string());
+            pending_plus = false;
+        }
+        else if pending_plus {
+            keys.push("+".to_string());
+            pending_plus = false;
+        }
+        else {
+            pending_plus = true;
+        }
+    }
+    keys
+}
+
+/// A function to remove the marker from
+/// the first character of the supplied text
+/// token. The rest of the text is returned as
+/// the `Text` variant of the `InlineStatement`
+/// enumeration. If there is no text left, `None`
+/// is returned.
+pub fn strip_marker(
+    token: &Token
+) -> Option<InlineStatement> {
+    let value: &str = token.value.as_deref().unwrap_or_default();
+    let marker_length: usize = value.chars().next()?.len_utf8();
+    let rest: &str = &value[marker_length..];
+    let location: Location = Location::new(
+        &Span::new(&token.span.end, &(token.span.start + marker_length)),
+        &token.end,
+        &Position::new(&token.start.line, &(token.start.column + 1))
+    );
+    Some(InlineStatement::Text(location, rest.to_string())).filter(|_| !rest.is_empty())
+}
+
+/// A function to remove the whitespace
+/// around the contents of a cell of a
+/// table and return the trimmed contents.
+pub fn trim_cell(
+    cell: Vec<InlineStatement>
+) -> Vec<InlineStatement> {
+    let last_idx: usize = cell.len().saturating_sub(1);
+    cell
+        .into_iter()
+        .enumerate()
+        .map(|(idx, statement)| match statement {
+            InlineStatement::Text(location, text) => {
+                let mut trimmed: &str = &text;
+                if idx == 0 {
+                    trimmed = trimmed.trim_start();
+                }
+                if idx == last_idx {
+                    trimmed = trimmed.trim_end();
+                }
+                InlineStatement::Text(location, trimmed.to_string())
+            },
+            statement => statement
+        })
+        .filter(|statement| !matches!(statement, InlineStatement::Text(_, text) if text.is_empty()))
+        .collect::<Vec<InlineStatement>>()
+}
+
+/// A function to read the alignment of
+/// a column from the supplied cell of the
+/// alignment row of a table. A cell holds
+/// dashes, optionally preceded or followed by
+/// a colon. A colon on the left aligns the
+/// column to the left, a colon on the right
+/// aligns it to the right, and colons on both
+/// sides center it. Without colons, the column
+/// has no alignment and `None` is returned. If
+/// the cell holds anything else, an error is
+/// returned.
+pub fn parse_alignment(
+    cell: &[InlineStatement],
+    row: &Location
+) -> Result<Option<Alignment>, JiraiErr> {
+    let text: String = match cell {
+        [InlineStatement::Text(_, text)] => text.clone(),
+        _ => String::new()
+    };
+    let dashes: &str = text.trim_start_matches(':').trim_end_matches(':');
+    if dashes.is_empty() || !dashes.chars().all(|c| c == '-'){
+        return Err::<Option<Alignment>, JiraiErr>(
+            JiraiErr::new(
+                &format!(
+                    "Invalid column alignment in the table row at position \"{}\"!",
+                    &row.start
+                )
+            )
+        );
+    }
+    let alignment: Option<Alignment> = match (text.starts_with(':'), text.ends_with(':')){
+        (true, true) => Some(Alignment::Center),
+        (true, false) => Some(Alignment::Left),
+        (false, true) => Some(Alignment::Right),
+        (false, false) => None
+    };
+    Ok(alignment)
+}
+
+/// A function to collect the task
+/// items that are not done yet from
+/// the supplied statements, including
+/// those in nested lists and in block
+/// quotes. The items are
+/// returned in the order they appear in,
+/// together with their locations.
+pub fn collect_open_tasks(
+    statements: &[Statement]
+) -> Vec<ListItem> {
+    let mut tasks: Vec<ListItem> = Vec::new();
+    for statement in statements {
+        let items: &[ListItem] = match statement {
+            Statement::UnorderedList(_, items) => items,
+            Statement::OrderedList(_, _, items) => items,
+            Statement::BlockQuote(_, children, _) => {
+                tasks.extend(collect_open_tasks(children));
+                continue;
+            },
+            _ => continue
+        };
+        for item in items {
+            if item.task == Some(false){
+                tasks.push(item.clone());
+            }
+            tasks.extend(collect_open_tasks(&item.children));
+        }
+    }
+    tasks
+}
+
+/// A function to collect the footnote
+/// references inside the supplied statements
+/// in the order they appear in. The identifier
+/// of each reference is returned together with
+/// its location.
+pub fn collect_footnote_references(
+    statements: &[Statement]
+) -> Vec<(String, Location)> {
+    let mut references: Vec<(String, Location)> = Vec::new();
+    for statement in statements {
+        match statement {
+            Statement::Heading(_, _, contents) |
+            Statement::Paragraph(_, contents) |
+            Statement::FootnoteDefinition(_, _, contents) => 
+                references.extend(collect_inline_references(contents)),
+            Statement::UnorderedList(_, items) |
+            Statement::OrderedList(_, _, items) => {
+                for item in items {
+                    references.extend(collect_inline_references(&item.contents));
+                    references.extend(collect_footnote_references(&item.children));
+                }
+            },
+            Statement::BlockQuote(_, children, attribution) => {
+                references.extend(collect_footnote_references(children));
+                references.extend(collect_inline_references(attribution.as_deref().unwrap_or_default()));
+            },
+            Statement::DefinitionList(_, items) => {
+                for item in items {
+                    references.extend(collect_inline_references(&item.term));
+                    for definition in &item.definitions {
+                        references.extend(collect_inline_references(definition));
+                    }
+                }
+            },
+            Statement::Table(_, _, header, rows) => {
+                for cell in header.cells.iter().chain(rows.iter().flat_map(|row| row.cells.iter())){
+                    references.extend(collect_inline_references(cell));
+                }
+            },
+            _ => {}
+        };
+    }
+    references
+}
+
+/// A function to collect the footnote
+/// references inside the supplied inline
+/// statements, including those nested in
+/// styled text. The identifier of
+/// each reference is returned together with
+/// its location.
+pub fn collect_inline_references(
+    inline_statements: &[InlineStatement]
+) -> Vec<(String, Location)> {
+    let mut references: Vec<(String, Location)> = Vec::new();
+    for inline_statement in inline_statements {
+        match inline_statement {
+            InlineStatement::FootnoteReference(location, id) => 
+                references.push((id.clone(), location.clone())),
+            InlineStatement::BoldText(_, nested) |
+            InlineStatement::ItalicText(_, nested) |
+            InlineStatement::HighlightText(_, nested) |
+            InlineStatement::UnderlineText(_, nested) |
+            InlineStatement::StrikethroughText(_, nested) => 
+                references.extend(collect_inline_references(nested)),
+            _ => {}
+        };
+    }
+    references
+}
+
+/// A function to collect the footnote
+/// definitions from the supplied statements,
+/// including those inside block quotes, in
+/// the order they appear in.
+pub fn collect_footnote_definitions(
+    statements: &[Statement]
+) -> Vec<Statement> {
+    let mut definitions: Vec<Statement> = Vec::new();
+    for statement in statements {
+        match statement {
+            Statement::FootnoteDefinition(_, _, _) => definitions.push(statement.clone()),
+            Statement::BlockQuote(_, children, _) => 
+                definitions.extend(collect_footnote_definitions(children)),
+            _ => {}
+        };
     }
+    definitions
 }
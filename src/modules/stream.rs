@@ -0,0 +1,173 @@
+/*
+Jirai by Alyx Shang.
+Licensed under the FSL v1.
+*/
+
+/// Importing the trait
+/// for buffered readers.
+use std::io::BufRead;
+
+/// Importing the structure
+/// holding information on
+/// a capture token.
+use super::lexer::Token;
+
+/// Importing the structure
+/// to catch and handle errors.
+use super::err::JiraiErr;
+
+/// Importing the function to
+/// lex a single token from a
+/// string of Jirai source code.
+use super::lexer::scan_token;
+
+/// Importing the structure to
+/// keep track of the lexer's place
+/// inside Jirai source code.
+use super::lexer::LexCursor;
+
+/// A structure to lazily tokenize
+/// Jirai source code read from any
+/// buffered reader. The source is read
+/// one line at a time and only tokens
+/// spanning several lines are buffered
+/// across lines, so neither the
+/// whole source nor the whole stream
+/// of tokens has to be held in memory.
+/// The `cursor` field tracks the line
+/// and column in the whole source and
+/// the byte offset inside the `buffer`
+/// field. The `base` field holds the byte
+/// offset at which the buffer starts. The
+/// `exhausted` field records whether the
+/// reader has reached its end.
+pub struct Lexer<R: BufRead>{
+    pub reader: R,
+    pub done: bool,
+    pub base: usize,
+    pub buffer: String,
+    pub exhausted: bool,
+    pub cursor: LexCursor
+}
+
+/// Implementing functions
+/// for the `Lexer` structure.
+impl<R: BufRead> Lexer<R> {
+
+    /// A function to create a new instance
+    /// of the `Lexer` structure reading from
+    /// the supplied reader and return it.
+    pub fn new(
+        reader: R
+    ) -> Lexer<R> {
+        Lexer::with_cursor(reader, &LexCursor::new(&0, &0, &0))
+    }
+
+    /// A function to create a new instance
+    /// of the `Lexer` structure reading from
+    /// the supplied reader and return it. The
+    /// lines, columns, and byte offsets of all
+    /// lexed tokens will count on from the
+    /// supplied cursor.
+    pub fn with_cursor(
+        reader: R,
+        cursor: &LexCursor
+    ) -> Lexer<R> {
+        Lexer{
+            reader,
+            done: false,
+            base: cursor.offset,
+            buffer: String::new(),
+            exhausted: false,
+            cursor: LexCursor::new(&cursor.line, &cursor.column, &0)
+        }
+    }
+
+    /// A function to return a cursor
+    /// pointing at the place in the whole
+    /// source at which the next token will
+    /// be lexed.
+    pub fn position(
+        &self
+    ) -> LexCursor {
+        LexCursor::new(
+            &self.cursor.line,
+            &self.cursor.column,
+            &(self.base + self.cursor.offset)
+        )
+    }
+
+    /// A function to drop the already lexed
+    /// part of the internal buffer and append
+    /// the next line from the reader to it. A
+    /// boolean reflecting whether anything was
+    /// read is returned. If reading fails, an
+    /// error is returned.
+    pub fn refill(
+        &mut self
+    ) -> Result<bool, JiraiErr> {
+        self.base += self.cursor.offset;
+        self.buffer.drain(..self.cursor.offset);
+        self.cursor.offset = 0;
+        match self.reader.read_line(&mut self.buffer){
+            Ok(read) => Ok(read > 0),
+            Err(e) => Err::<bool, JiraiErr>(
+                JiraiErr::new(&e.to_string())
+            )
+        }
+    }
+}
+
+/// Implementing functions
+/// for a `Lexer` structure
+/// reading from a string.
+impl<'a> Lexer<&'a [u8]> {
+
+    /// A function to create a new instance
+    /// of the `Lexer` structure reading from
+    /// a string of Jirai source code and
+    /// return it.
+    pub fn from_string(
+        sub: &'a str
+    ) -> Lexer<&'a [u8]> {
+        Lexer::new(sub.as_bytes())
+    }
+}
+
+/// Implementing the `Iterator`
+/// trait for the `Lexer` structure.
+/// Once an error has been returned,
+/// no more tokens are lexed.
+impl<R: BufRead> Iterator for Lexer<R> {
+    type Item = Result<Token, JiraiErr>;
+    fn next(
+        &mut self
+    ) -> Option<Result<Token, JiraiErr>> {
+        while !self.done {
+            if let Some(scanned) = scan_token(&self.buffer, &mut self.cursor, &self.exhausted){
+                let result: Result<Token, JiraiErr> = scanned.map(
+                    |borrowed| {
+                        let mut token: Token = borrowed.to_token();
+                        token.span.start += self.base;
+                        token.span.end += self.base;
+                        token
+                    }
+                );
+                self.done = result.is_err();
+                return Some(result);
+            }
+            if self.exhausted {
+                self.done = true;
+                break;
+            }
+            match self.refill(){
+                Ok(read) => self.exhausted = !read,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err::<Token, JiraiErr>(e));
+                }
+            };
+        }
+        None
+    }
+}
@@ -3,26 +3,72 @@ Jirai by Alyx Shang.
 Licensed under the FSL v1.
 */
 
+/// Importing the structure
+/// to open files on disk.
+use std::fs::File;
+
+/// Importing the structure
+/// for ranges of indices.
+use std::ops::Range;
+
 /// Importing the structure
 /// to model paths on disk.
 use std::path::PathBuf;
 
+/// Importing the data structure
+/// to encapsulate data about the
+/// byte offsets of a token.
+use super::lexer::Span;
+
+/// Importing the structure
+/// for buffered reading.
+use std::io::BufReader;
+
 /// Importing the structure
 /// holding information on
 /// a capture token.
 use super::lexer::Token;
 
+/// Importing the structure
+/// to lazily tokenize Jirai
+/// source code.
+use super::stream::Lexer;
+
+/// Importing the structure
+/// to catch and handle errors.
+use super::err::JiraiErr;
+
 /// Importing the structure
 /// responsible for parsing
 /// a stream of tokens.
 use super::parser::Parser;
 
+/// Importing the structure
+/// for a problem found in
+/// Jirai source code.
+use super::err::Diagnostic;
+
 /// Importing the data structure
 /// to encapsulate data about the
 /// line and column information
 /// of a token.
 use super::lexer::Position;
 
+/// Importing the structure to
+/// keep track of the lexer's place
+/// inside Jirai source code.
+use super::lexer::LexCursor;
+
+/// Importing the structure
+/// encapsulating information
+/// on a parsed list item.
+use super::parser::ListItem;
+
+/// Importing the structure
+/// encapsulating the location
+/// of a parsed statement.
+use super::parser::Location;
+
 /// Importing the enumeration
 /// containing all possible types
 /// Jirai tokens.
@@ -33,6 +79,11 @@ use super::lexer::TokenType;
 /// from disk as a string.
 use std::fs::read_to_string;
 
+/// Importing the function to
+/// lex a single token from a
+/// string of Jirai source code.
+use super::lexer::scan_token;
+
 /// Importing the enumeration
 /// describing all possible types
 /// of block-level statements
@@ -50,17 +101,128 @@ use super::compiler::to_html;
 /// received.
 use super::parser::SourceType;
 
+/// Importing the enumeration
+/// listing all types of trivia.
+use super::lossless::TriviaType;
+
+/// Importing the structure
+/// holding information on a
+/// token borrowing from the source.
+use super::lexer::BorrowedToken;
+
+/// Importing the structure
+/// to describe an edit of
+/// Jirai source code.
+use super::incremental::TextEdit;
+
+/// Importing the structure
+/// encapsulating information
+/// on a parsed term of a
+/// definition list.
+use super::parser::DefinitionItem;
+
+/// Importing the structure
+/// holding tokens and lex errors
+/// from the lexer's recovery mode.
+use super::lexer::RecoveredTokens;
+
 /// Importing the function to
 /// tokenize a string of Jirai
 /// source code.
 use super::lexer::tokenize_string;
 
+/// Importing the enumeration
+/// describing all possible types
+/// of inline statements Jirai source
+/// code can contain.
+use super::parser::InlineStatement;
+
+/// Importing the structure
+/// holding a token and its
+/// trivia.
+use super::lossless::LosslessToken;
+
 /// Importing the structure that
 /// takes an AST from parsed Jirai
 /// source code and generates HTML
 /// code from this.
 use super::html::HTMLCodeGenerator;
 
+/// Importing the function to
+/// tokenize a string of Jirai
+/// source code without copying.
+use super::lexer::tokenize_borrowed;
+
+/// Importing the function to
+/// collect open task items.
+use super::parser::collect_open_tasks;
+
+/// Importing the function to
+/// tokenize Jirai source code
+/// without stopping at errors.
+use super::lexer::tokenize_recovering;
+
+/// Importing the function to
+/// rebuild Jirai source code from
+/// a lossless stream of tokens.
+use super::lossless::tokens_to_source;
+
+/// Importing the structure
+/// holding Jirai source code
+/// and its tokens.
+use super::incremental::LexedDocument;
+
+/// Importing the structure
+/// holding statements and parse
+/// errors from the parser's
+/// recovery mode.
+use super::parser::RecoveredStatements;
+
+/// Importing the function to
+/// tokenize a string of Jirai
+/// source code without losing text.
+use super::lossless::tokenize_lossless;
+
+/// Importing the function to
+/// convert a byte offset into
+/// a character column.
+use super::lexer::byte_offset_to_char_column;
+
+/// Importing the function to
+/// convert a line and character
+/// column into a byte offset.
+use super::lexer::char_column_to_byte_offset;
+
+/// Importing the function to
+/// convert a byte offset into
+/// a UTF-16 column.
+use super::lexer::byte_offset_to_utf16_column;
+
+/// Importing the function to
+/// convert a line and UTF-16
+/// column into a byte offset.
+use super::lexer::utf16_column_to_byte_offset;
+
+/// Importing the function to
+/// convert a byte offset into a
+/// character column inside its line.
+use super::lexer::byte_offset_to_line_char_column;
+
+/// Importing the function to
+/// convert a line and a character
+/// column inside it into a byte offset.
+use super::lexer::line_char_column_to_byte_offset;
+
+/// Importing the function to
+/// convert a byte offset into a
+/// UTF-16 column inside its line.
+use super::lexer::byte_offset_to_line_utf16_column;
+
+/// Importing the function to
+/// convert a line and a UTF-16
+/// column inside it into a byte offset.
+use super::lexer::line_utf16_column_to_byte_offset;
+
 /// A function to test the 
 /// Jirai tokenizer.
 #[test]
@@ -68,372 +230,445 @@ pub fn test_lexer(){
     let mut example_home: PathBuf = PathBuf::new();
     example_home.push(env!("CARGO_MANIFEST_DIR"));
     example_home.push("example/example.jirai");
-    let sample_code: String = read_to_string(&example_home.as_path())
+    let sample_code: String = read_to_string(example_home.as_path())
         .expect("Could not read file contents.");
     let tokens: Vec<Token> = tokenize_string(&sample_code)
         .expect("Could not tokenize sample string.");
     let expected: Vec<Token> = vec![
         Token::new(
+            &Span::new(&5,&0),
             &Position::new(&0,&5),
             &Position::new(&0,&0),
             &TokenType::DocumentLimiter,
             &None),
         Token::new(
+            &Span::new(&6,&5),
             &Position::new(&0,&6),
             &Position::new(&0,&5),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&8,&6),
             &Position::new(&1,&8),
             &Position::new(&1,&6),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&9,&8),
             &Position::new(&1,&9),
             &Position::new(&1,&8),
             &TokenType::UserString,
             &Some(" ".to_string())), 
         Token::new(
+            &Span::new(&10,&9),
             &Position::new(&1,&10),
             &Position::new(&1,&9),
             &TokenType::ItalicText,
             &None), 
         Token::new(
+            &Span::new(&19,&10),
             &Position::new(&1,&19),
             &Position::new(&1,&10),
             &TokenType::UserString,
             &Some("Heading I".to_string())), 
         Token::new(
+            &Span::new(&20,&19),
             &Position::new(&1,&20),
             &Position::new(&1,&19),
             &TokenType::ItalicText,
             &None), 
         Token::new(
+            &Span::new(&21,&20),
             &Position::new(&1,&21),
             &Position::new(&1,&20),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&76,&21),
             &Position::new(&2,&76),
             &Position::new(&2,&21),
             &TokenType::UserString,
             &Some("Lorem ipsum sit dolor amet. Lorem ipsum sit dolor amet.".to_string())), 
         Token::new(
+            &Span::new(&77,&76),
             &Position::new(&2,&77),
             &Position::new(&2,&76),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&78,&77),
             &Position::new(&3,&78),
             &Position::new(&3,&77),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&80,&78),
             &Position::new(&4,&80),
             &Position::new(&4,&78),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&82,&80),
             &Position::new(&4,&82),
             &Position::new(&4,&80),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&83,&82),
             &Position::new(&4,&83),
             &Position::new(&4,&82),
             &TokenType::UserString,
             &Some(" ".to_string())), 
         Token::new(
+            &Span::new(&84,&83),
             &Position::new(&4,&84),
             &Position::new(&4,&83),
             &TokenType::BoldText,
             &None), 
         Token::new(
+            &Span::new(&94,&84),
             &Position::new(&4,&94),
             &Position::new(&4,&84),
             &TokenType::UserString,
             &Some("Heading II".to_string())), 
         Token::new(
+            &Span::new(&95,&94),
             &Position::new(&4,&95),
             &Position::new(&4,&94),
             &TokenType::BoldText,
             &None), 
         Token::new(
+            &Span::new(&96,&95),
             &Position::new(&4,&96),
             &Position::new(&4,&95),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&161,&96),
             &Position::new(&5,&161),
             &Position::new(&5,&96),
             &TokenType::UserString,
             &Some("Lorem ipsum sit dolor amet. Lorem ipsum sit dolor amet. This text".to_string())), 
         Token::new(
+            &Span::new(&162,&161),
             &Position::new(&5,&162),
             &Position::new(&5,&161),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&173,&162),
             &Position::new(&6,&173),
             &Position::new(&6,&162),
             &TokenType::UserString,
             &Some("contains a ".to_string())), 
         Token::new(
+            &Span::new(&174,&173),
             &Position::new(&6,&174),
             &Position::new(&6,&173),
             &TokenType::OpenCurly,
             &None), 
         Token::new(
+            &Span::new(&175,&174),
             &Position::new(&6,&175),
             &Position::new(&6,&174),
             &TokenType::LinkMarker,
             &None), 
         Token::new(
+            &Span::new(&176,&175),
             &Position::new(&6,&176),
             &Position::new(&6,&175),
             &TokenType::OpenSquare,
             &None), 
         Token::new(
+            &Span::new(&180,&176),
             &Position::new(&6,&180),
             &Position::new(&6,&176),
             &TokenType::UserString,
             &Some("link".to_string())), 
         Token::new(
+            &Span::new(&181,&180),
             &Position::new(&6,&181),
             &Position::new(&6,&180),
             &TokenType::CloseSquare,
             &None), 
         Token::new(
+            &Span::new(&182,&181),
             &Position::new(&6,&182),
             &Position::new(&6,&181),
             &TokenType::OpenSquare,
             &None), 
         Token::new(
+            &Span::new(&203,&182),
             &Position::new(&6,&203),
             &Position::new(&6,&182),
             &TokenType::UserString, 
             &Some("https://alyxshang.boo".to_string())), 
         Token::new(
+            &Span::new(&204,&203),
             &Position::new(&6,&204),
             &Position::new(&6,&203),
             &TokenType::CloseSquare,
             &None), 
         Token::new(
+            &Span::new(&205,&204),
             &Position::new(&6,&205),
             &Position::new(&6,&204),
             &TokenType::CloseCurly,
             &None), 
         Token::new(
+            &Span::new(&206,&205),
             &Position::new(&6,&206),
             &Position::new(&6,&205),
             &TokenType::UserString,
             &Some(".".to_string())), 
         Token::new(
+            &Span::new(&207,&206),
             &Position::new(&6,&207),
             &Position::new(&6,&206),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&208,&207),
             &Position::new(&7,&208),
             &Position::new(&7,&207),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&210,&208),
             &Position::new(&8,&210),
             &Position::new(&8,&208),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&212,&210),
             &Position::new(&8,&212),
             &Position::new(&8,&210),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&214,&212),
             &Position::new(&8,&214),
             &Position::new(&8,&212),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&215,&214),
             &Position::new(&8,&215),
             &Position::new(&8,&214),
             &TokenType::UserString,
             &Some(" ".to_string())), 
         Token::new(
+            &Span::new(&216,&215),
             &Position::new(&8,&216),
             &Position::new(&8,&215),
             &TokenType::ItalicText,
             &None), 
         Token::new(
+            &Span::new(&227,&216),
             &Position::new(&8,&227),
             &Position::new(&8,&216),
             &TokenType::UserString,
             &Some("Heading III".to_string())), 
         Token::new(
+            &Span::new(&228,&227),
             &Position::new(&8,&228),
             &Position::new(&8,&227),
             &TokenType::ItalicText,
             &None), 
         Token::new(
+            &Span::new(&229,&228),
             &Position::new(&8,&229),
             &Position::new(&8,&228),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&268,&229),
             &Position::new(&9,&268),
             &Position::new(&9,&229),
             &TokenType::UserString,
             &Some("This paragraph contains my profile pic.".to_string())), 
         Token::new(
+            &Span::new(&269,&268),
             &Position::new(&9,&269),
             &Position::new(&9,&268),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&270,&269),
             &Position::new(&10,&270),
             &Position::new(&10,&269),
             &TokenType::OpenCurly,
             &None), 
         Token::new(
+            &Span::new(&271,&270),
             &Position::new(&10,&271),
             &Position::new(&10,&270),
             &TokenType::ImageMarker,
             &None), 
         Token::new(
+            &Span::new(&272,&271),
             &Position::new(&10,&272),
             &Position::new(&10,&271),
             &TokenType::OpenSquare,
             &None), 
         Token::new(
+            &Span::new(&278,&272),
             &Position::new(&10,&278),
             &Position::new(&10,&272),
             &TokenType::UserString,
             &Some("my pfp".to_string())), 
         Token::new(
+            &Span::new(&279,&278),
             &Position::new(&10,&279),
             &Position::new(&10,&278),
             &TokenType::CloseSquare,
             &None), 
         Token::new(
+            &Span::new(&280,&279),
             &Position::new(&10,&280),
             &Position::new(&10,&279),
             &TokenType::OpenSquare,
             &None), 
         Token::new(
+            &Span::new(&333,&280),
             &Position::new(&10,&333),
             &Position::new(&10,&280),
             &TokenType::UserString,
             &Some("https://avatars.githubusercontent.com/u/179976644?v=4".to_string())), 
         Token::new(
+            &Span::new(&334,&333),
             &Position::new(&10,&334),
             &Position::new(&10,&333),
             &TokenType::CloseSquare,
             &None), 
         Token::new(
+            &Span::new(&335,&334),
             &Position::new(&10,&335),
             &Position::new(&10,&334),
             &TokenType::CloseCurly,
             &None), 
         Token::new(
+            &Span::new(&336,&335),
             &Position::new(&10,&336),
             &Position::new(&10,&335),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&337,&336),
             &Position::new(&11,&337),
             &Position::new(&11,&336),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&339,&337),
             &Position::new(&12,&339),
             &Position::new(&12,&337),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&341,&339),
             &Position::new(&12,&341),
             &Position::new(&12,&339),
             &TokenType::HeadingMarker,
             &None), 
         Token::new(
+            &Span::new(&352,&341),
             &Position::new(&12,&352),
             &Position::new(&12,&341),
             &TokenType::UserString,
             &Some(" Heading IV".to_string())), 
         Token::new(
+            &Span::new(&353,&352),
             &Position::new(&12,&353),
             &Position::new(&12,&352),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&354,&353),
             &Position::new(&13,&354),
             &Position::new(&13,&353),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&369,&354),
             &Position::new(&14,&369),
             &Position::new(&14,&354),
             &TokenType::UserString,
             &Some("This is a list!".to_string())), 
         Token::new(
+            &Span::new(&370,&369),
             &Position::new(&14,&370),
             &Position::new(&14,&369),
             &TokenType::NewLine, 
             &None), 
         Token::new(
+            &Span::new(&371,&370),
             &Position::new(&15,&371),
             &Position::new(&15,&370),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&372,&371),
             &Position::new(&16,&372),
             &Position::new(&16,&371),
             &TokenType::ListMarker,
             &None), 
         Token::new(
+            &Span::new(&385,&372),
             &Position::new(&16,&385),
             &Position::new(&16,&372),
             &TokenType::UserString,
             &Some(" List item 1.".to_string())), 
         Token::new(
+            &Span::new(&386,&385),
             &Position::new(&16,&386),
             &Position::new(&16,&385),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&387,&386),
             &Position::new(&17,&387),
             &Position::new(&17,&386),
             &TokenType::ListMarker,
             &None), 
         Token::new(
+            &Span::new(&400,&387),
             &Position::new(&17,&400),
             &Position::new(&17,&387),
             &TokenType::UserString,
             &Some(" List item 2.".to_string())), 
         Token::new(
+            &Span::new(&401,&400),
             &Position::new(&17,&401),
             &Position::new(&17,&400),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&402,&401),
             &Position::new(&18,&402),
             &Position::new(&18,&401),
             &TokenType::ListMarker,
             &None), 
         Token::new(
+            &Span::new(&415,&402),
             &Position::new(&18,&415),
             &Position::new(&18,&402),
             &TokenType::UserString,
             &Some(" List item 3.".to_string())), 
         Token::new(
+            &Span::new(&416,&415),
             &Position::new(&18,&416),
             &Position::new(&18,&415),
             &TokenType::NewLine,
             &None), 
         Token::new(
+            &Span::new(&421,&416),
             &Position::new(&19,&421),
             &Position::new(&19,&416),
             &TokenType::DocumentLimiter,
             &None), 
         Token::new(
+            &Span::new(&422,&421),
             &Position::new(&19,&422),
             &Position::new(&19,&421),
             &TokenType::NewLine,
@@ -449,7 +684,7 @@ pub fn test_parser(){
     let mut example_home: PathBuf = PathBuf::new();
     example_home.push(env!("CARGO_MANIFEST_DIR"));
     example_home.push("example/example.jirai");
-    let sample_code: String = read_to_string(&example_home.as_path())
+    let sample_code: String = read_to_string(example_home.as_path())
         .expect("Could not read file contents.");
     let tokens: Vec<Token> = tokenize_string(&sample_code)
         .expect("Could not tokenize sample string.");
@@ -467,6 +702,27 @@ pub fn test_parser(){
 /// `HTMLCodeGenerator` structure.
 #[test]
 pub fn test_html_generator(){
+    let source: &str = "<3 Links\nSee {#[home][https://alyxshang.boo]}!";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements[1].location().start, Position::new(&1, &9));
+    assert_eq!(statements[1].location().span.slice(source), Ok("See {#[home][https://alyxshang.boo]}!"));
+    let mut generator: HTMLCodeGenerator = HTMLCodeGenerator::new(&true, &false, &statements)
+        .expect("Could not create generator.");
+    assert_eq!(
+        generator.generate(),
+        Ok("<h1> Links</h1><p>See <a href=\"https://alyxshang.boo\">home</a>!</p>".to_string())
+    );
+    let mut generator: HTMLCodeGenerator = HTMLCodeGenerator::new(&true, &true, &statements)
+        .expect("Could not create generator.");
+    assert_eq!(
+        generator.generate(),
+        Err(JiraiErr::new("No \"alt\" text supplied to link \"https://alyxshang.boo\" at position \"1:13\"!"))
+    );
 }
 
 /// The function to test all functions
@@ -475,5 +731,1302 @@ pub fn test_html_generator(){
 /// formats of code.
 #[test]
 pub fn test_compiler(){
+    let mut example_home: PathBuf = PathBuf::new();
+    example_home.push(env!("CARGO_MANIFEST_DIR"));
+    example_home.push("example/example.jirai");
+    let sample_code: String = read_to_string(example_home.as_path())
+        .expect("Could not read file contents.");
+    let html: String = to_html(&sample_code, &true, &false, &SourceType::Document)
+        .expect("Could not compile sample document.");
+    assert!(html.starts_with("<h1> <i>Heading I</i></h1>"));
+    assert!(html.contains("<a href=\"https://alyxshang.boo\">link</a>"));
+    assert!(!html.contains("(^-^)"));
+}
+
+/// A function to test that escaped
+/// reserved characters and lone hyphens
+/// and carets are lexed as text.
+#[test]
+pub fn test_lexer_escapes(){
+    let tokens: Vec<Token> = tokenize_string("well-known \\*stars\\* ^_^ \\<3")
+        .expect("Could not tokenize escaped string.");
+    let expected: Vec<Token> = vec![
+        Token::new(
+            &Span::new(&28,&0),
+            &Position::new(&0,&28),
+            &Position::new(&0,&0),
+            &TokenType::UserString,
+            &Some("well-known *stars* ^_^ <3".to_string()))
+    ];
+    assert_eq!(tokens, expected);
+    let tokens: Vec<Token> = tokenize_string("a\\b")
+        .expect("Could not tokenize backslash string.");
+    assert_eq!(tokens[0].value, Some("a\\b".to_string()));
+    assert_eq!(
+        to_html("\\<b\\>bold\\</b\\> & \"x\" {#[a\\<][https://a.b/?q=\"x\"&r]}", &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<p>&lt;b&gt;bold&lt;/b&gt; &amp; &quot;x&quot; ",
+                "<a href=\"https://a.b/?q=&quot;x&quot;&amp;r\">a&lt;</a></p>"
+            ).to_string()
+        )
+    );
+}
+
+/// A function to test the byte
+/// offsets attached to tokens and
+/// the conversions between byte offsets,
+/// character columns and UTF-16 columns.
+#[test]
+pub fn test_lexer_spans(){
+    let source: &str = "<3 Ünïcode 🎀\n*bold*";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    for token in &tokens {
+        assert!(token.span.slice(source).is_ok());
+    }
+    assert_eq!(tokens[1].span, Span::new(&17, &2));
+    assert_eq!(tokens[1].span.slice(source), Ok(" Ünïcode 🎀"));
+    assert_eq!(tokens[3].span, Span::new(&19, &18));
+    assert_eq!(byte_offset_to_char_column(source, &13), Ok(11));
+    assert_eq!(byte_offset_to_utf16_column(source, &17), Ok(13));
+    assert_eq!(byte_offset_to_char_column(source, &19), Ok(14));
+    assert_eq!(char_column_to_byte_offset(source, &0, &11), Ok(13));
+    assert_eq!(utf16_column_to_byte_offset(source, &0, &13), Ok(17));
+    assert_eq!(char_column_to_byte_offset(source, &1, &19), Ok(source.len()));
+    assert!(char_column_to_byte_offset(source, &1, &6).is_err());
+    assert!(utf16_column_to_byte_offset(source, &0, &12).is_err());
+    assert!(byte_offset_to_char_column(source, &14).is_err());
+    assert_eq!(byte_offset_to_line_char_column(source, &13), Ok(11));
+    assert_eq!(byte_offset_to_line_char_column(source, &19), Ok(1));
+    assert_eq!(byte_offset_to_line_utf16_column(source, &17), Ok(13));
+    assert_eq!(byte_offset_to_line_utf16_column(source, &19), Ok(1));
+    assert_eq!(line_char_column_to_byte_offset(source, &1, &1), Ok(19));
+    assert_eq!(line_utf16_column_to_byte_offset(source, &0, &13), Ok(17));
+    assert_eq!(line_utf16_column_to_byte_offset(source, &1, &6), Ok(source.len()));
+    assert!(line_char_column_to_byte_offset(source, &1, &7).is_err());
+    assert!(line_utf16_column_to_byte_offset(source, &0, &12).is_err());
+    assert!(byte_offset_to_line_utf16_column(source, &14).is_err());
+    let source: &str = "ab\ncd *e*\nf 🎀 *g*";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    for token in &tokens {
+        assert_eq!(
+            char_column_to_byte_offset(source, &token.start.line, &token.start.column),
+            Ok(token.span.start)
+        );
+        assert_eq!(byte_offset_to_char_column(source, &token.span.start), Ok(token.start.column));
+        let column: usize = byte_offset_to_line_char_column(source, &token.span.start)
+            .expect("Could not convert byte offset.");
+        assert_eq!(
+            line_char_column_to_byte_offset(source, &token.start.line, &column),
+            Ok(token.span.start)
+        );
+    }
+    let last: &Token = &tokens[tokens.len() - 2];
+    assert_eq!(last.start.line, 2);
+    assert_eq!(last.span.slice(source), Ok("g"));
+    assert_eq!(char_column_to_byte_offset(source, &1, &6), Ok(6));
+}
+
+/// A function to test that the
+/// borrowing lexer slices its values
+/// out of the source and converts into
+/// the same owned tokens.
+#[test]
+pub fn test_lexer_borrowed(){
+    let source: &str = "<3 \\*Star\\*\r\n~ item";
+    let borrowed: Vec<BorrowedToken> = tokenize_borrowed(source)
+        .expect("Could not tokenize string.");
+    let types: Vec<TokenType> = borrowed
+        .iter()
+        .map(|token| token.token_type.clone())
+        .collect::<Vec<TokenType>>();
+    assert_eq!(
+        types,
+        vec![
+            TokenType::HeadingMarker,
+            TokenType::UserString,
+            TokenType::NewLine,
+            TokenType::ListMarker,
+            TokenType::UserString
+        ]
+    );
+    let value: &str = borrowed[1].value.expect("Expected a value.");
+    assert_eq!(value, " \\*Star\\*");
+    assert!(source.as_bytes().as_ptr_range().contains(&value.as_ptr()));
+    assert_eq!(borrowed[3].start, Position::new(&1, &13));
+    let owned: Vec<Token> = borrowed
+        .iter()
+        .map(|token| token.to_token())
+        .collect::<Vec<Token>>();
+    assert_eq!(owned[1].value, Some(" *Star*".to_string()));
+    assert_eq!(owned, tokenize_string(source).expect("Could not tokenize string."));
+}
+
+/// A function to test that the
+/// streaming lexer yields the same
+/// tokens as the eager one.
+#[test]
+pub fn test_stream_lexer(){
+    let mut example_home: PathBuf = PathBuf::new();
+    example_home.push(env!("CARGO_MANIFEST_DIR"));
+    example_home.push("example/example.jirai");
+    let sample_code: String = read_to_string(example_home.as_path())
+        .expect("Could not read file contents.");
+    let file: File = File::open(example_home.as_path())
+        .expect("Could not open file.");
+    let streamed: Vec<Token> = Lexer::new(BufReader::new(file))
+        .collect::<Result<Vec<Token>, JiraiErr>>()
+        .expect("Could not tokenize sample file.");
+    let expected: Vec<Token> = tokenize_string(&sample_code)
+        .expect("Could not tokenize sample string.");
+    assert_eq!(streamed, expected);
+    for source in ["\\~ a\r\n~ 🎀\rb", "a\n>>>", "\r\n>>>", "<<<rust x\r\nfn <a>\n\n  >>>\nb"] {
+        let streamed: Vec<Token> = Lexer::from_string(source)
+            .collect::<Result<Vec<Token>, JiraiErr>>()
+            .expect("Could not tokenize string.");
+        assert_eq!(streamed, tokenize_string(source).expect("Could not tokenize string."));
+    }
+    let block: String = format!("<<<\n{}>>>\n", "let x = 1;\n".repeat(20000));
+    let streamed: Vec<Token> = Lexer::from_string(&block)
+        .collect::<Result<Vec<Token>, JiraiErr>>()
+        .expect("Could not tokenize string.");
+    assert_eq!(streamed, tokenize_string(&block).expect("Could not tokenize string."));
+    let mut cursor: LexCursor = LexCursor::new(&0, &0, &0);
+    assert!(scan_token("<<<\na\n", &mut cursor, &false).is_none());
+    assert_eq!(cursor.searched, 6);
+    assert!(scan_token("<<<\na\n>>>\n", &mut cursor.clone(), &false).is_some());
+    cursor.searched = 10;
+    assert!(scan_token("<<<\na\n>>>\nb", &mut cursor, &false).is_none());
+}
+
+/// A function to test that re-tokenizing
+/// edited source code incrementally gives
+/// the same tokens as tokenizing the edited
+/// source code from scratch.
+#[test]
+pub fn test_incremental_lexer(){
+    let mut example_home: PathBuf = PathBuf::new();
+    example_home.push(env!("CARGO_MANIFEST_DIR"));
+    example_home.push("example/example.jirai");
+    let sample_code: String = read_to_string(example_home.as_path())
+        .expect("Could not read file contents.");
+    let mut document: LexedDocument = LexedDocument::new(&sample_code)
+        .expect("Could not tokenize sample string.");
+    let inserts: Vec<&str> = vec![
+        "", "x", "*", "\n", "\n\n~ 🎀 ", "<3", "\r\n", "(^-^)", "\\", "é$"
+    ];
+    let mut seed: usize = 7;
+    for round in 0..200 {
+        seed = (seed * 1103515245 + 12345) % 2147483648;
+        let boundaries: Vec<usize> = (0..=document.source.len())
+            .filter(|idx| document.source.is_char_boundary(*idx))
+            .collect::<Vec<usize>>();
+        let start: usize = boundaries[seed % boundaries.len()];
+        let end: usize = boundaries
+            .iter()
+            .copied()
+            .rfind(|idx| *idx >= start && *idx <= start + seed / 7 % 12)
+            .unwrap_or(start);
+        let edit: TextEdit = TextEdit::new(
+            &Span::new(&end, &start),
+            inserts[round % inserts.len()]
+        );
+        let edited: String = format!(
+            "{}{}{}",
+            &document.source[..start],
+            &edit.text,
+            &document.source[end..]
+        );
+        let relexed: Result<Vec<Token>, JiraiErr> = if edited.is_empty(){
+            Ok(Vec::new())
+        }
+        else {
+            tokenize_string(&edited)
+        };
+        match relexed {
+            Ok(expected) => {
+                document.apply_edit(&edit)
+                    .expect("Could not apply edit.");
+                assert_eq!(document.tokens, expected);
+            },
+            Err(_) => {
+                let unchanged: LexedDocument = document.clone();
+                assert!(document.apply_edit(&edit).is_err());
+                assert_eq!(document, unchanged);
+            }
+        };
+    }
+    let mut document: LexedDocument = LexedDocument::new(&sample_code)
+        .expect("Could not tokenize sample string.");
+    let edit: TextEdit = TextEdit::new(&Span::new(&2, &0), "ab");
+    let changed: Range<usize> = document.apply_edit(&edit)
+        .expect("Could not apply edit.");
+    assert!(changed.end < document.tokens.len());
+    let edits: Vec<(&str, usize, usize, &str)> = vec![
+        ("b c}~~\n>(", 6, 6, "\n>>>"),
+        ("<<<x\nfn\n>>>\nb", 5, 5, ">>>\n"),
+        ("|a (~.~( x\ny )~.~)|b\n", 11, 12, "z"),
+        ("==a (~.~( b== x\ny )~.~) c==\n", 16, 17, "z"),
+        ("==a (~.~( x\ny )~.~) ==b==\n", 12, 13, "z")
+    ];
+    for (source, start, end, text) in edits {
+        let mut document: LexedDocument = LexedDocument::new(source)
+            .expect("Could not tokenize string.");
+        document.apply_edit(&TextEdit::new(&Span::new(&end, &start), text))
+            .expect("Could not apply edit.");
+        assert_eq!(Ok(document.tokens), tokenize_string(&document.source));
+    }
+}
+
+/// A function to test that a lossless
+/// stream of tokens marks trivia and
+/// rebuilds the exact source.
+#[test]
+pub fn test_lossless_lexer(){
+    let mut example_home: PathBuf = PathBuf::new();
+    example_home.push(env!("CARGO_MANIFEST_DIR"));
+    example_home.push("example/example.jirai");
+    let sample_code: String = read_to_string(example_home.as_path())
+        .expect("Could not read file contents.");
+    let sources: Vec<&str> = vec![
+        &sample_code,
+        "  ~ item  \r\n\n \t\n  text\\*  *bold*\t\n   ",
+        " \n\t\n",
+        "a"
+    ];
+    for source in sources {
+        let tokens: Vec<LosslessToken> = tokenize_lossless(source)
+            .expect("Could not tokenize string.");
+        assert_eq!(tokens_to_source(&tokens), source);
+    }
+    let tokens: Vec<LosslessToken> = tokenize_lossless("  ~ item  \n\n  text")
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens[0].token.token_type, TokenType::ListMarker);
+    assert_eq!(tokens[0].leading[0].trivia_type, TriviaType::Indentation);
+    assert_eq!(tokens[1].token.value, Some(" item".to_string()));
+    assert_eq!(tokens[1].trailing[0].text, "  ");
+    assert_eq!(tokens[1].trailing[0].trivia_type, TriviaType::Whitespace);
+    assert_eq!(tokens[3].leading[0].trivia_type, TriviaType::BlankLine);
+    assert_eq!(tokens[3].leading[1].trivia_type, TriviaType::Indentation);
+    assert_eq!(tokens[3].token.value, Some("text".to_string()));
+    assert_eq!(tokens[3].token.start, Position::new(&2, &14));
+}
+
+/// A function to test that comments
+/// are lexed, streamed, kept as trivia,
+/// and dropped or emitted by the
+/// `HTMLCodeGenerator` structure.
+#[test]
+pub fn test_comments(){
+    let source: &str = "(~.~) A note.\nSome *text*(~.~( a\nblock )~.~) here. (~.~) tail\n(~.~(\n\n)~.~)\n";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens[0].token_type, TokenType::Comment);
+    assert_eq!(tokens[0].value, Some(" A note.".to_string()));
+    assert_eq!(tokens[6].token_type, TokenType::Comment);
+    assert_eq!(tokens[6].value, Some(" a\nblock ".to_string()));
+    assert_eq!(tokens[6].end, Position::new(&2, &44));
+    assert_eq!(tokens[9].start.line, 2);
+    assert_eq!(tokens[10].start.line, 3);
+    assert_eq!(tokens[11].start.line, 5);
+    let streamed: Vec<Token> = Lexer::from_string(source)
+        .collect::<Result<Vec<Token>, JiraiErr>>()
+        .expect("Could not tokenize string.");
+    assert_eq!(streamed, tokens);
+    let lossless: Vec<LosslessToken> = tokenize_lossless(source)
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens_to_source(&lossless), source);
+    assert_eq!(lossless[0].leading[0].trivia_type, TriviaType::Comment);
+    let fragments: Vec<&str> = vec!["a", " ", "\t ", "(~.~(x)~.~)", "(~.~) c", "\n", "*b*", "~ "];
+    for first in &fragments {
+        for second in &fragments {
+            for third in &fragments {
+                let combined: String = format!("{}{}{}{}", first, second, third, first);
+                if let Ok(lossless) = tokenize_lossless(&combined){
+                    assert_eq!(tokens_to_source(&lossless), combined);
+                }
+            }
+        }
+    }
+    let trailing: Vec<LosslessToken> = tokenize_lossless("a (~.~(x)~.~)  ")
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens_to_source(&trailing), "a (~.~(x)~.~)  ");
+    assert_eq!(trailing[0].trailing[1].trivia_type, TriviaType::Comment);
+    assert_eq!(trailing[0].trailing[2].trivia_type, TriviaType::Whitespace);
+    assert!(tokenize_string("(~.~( never closed").is_err());
+    assert!(Lexer::from_string("a\n(~.~( never\nclosed").any(|token| token.is_err()));
+    let long: String = format!("(~.~({})~.~)\n", "a note\n".repeat(20000));
+    let streamed: Vec<Token> = Lexer::from_string(&long)
+        .collect::<Result<Vec<Token>, JiraiErr>>()
+        .expect("Could not tokenize string.");
+    assert_eq!(streamed, tokenize_string(&long).expect("Could not tokenize string."));
+    let mut cursor: LexCursor = LexCursor::new(&0, &0, &0);
+    assert!(scan_token("(~.~( a\n", &mut cursor, &false).is_none());
+    assert_eq!(cursor.searched, 8);
+    assert!(scan_token("(~.~( a\n)~.~)", &mut cursor, &false).is_some());
+    let unclosed: RecoveredTokens = tokenize_recovering(&"(~.~( ".repeat(20000));
+    assert_eq!(unclosed.errors.len(), 20000);
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(
+        statements[0],
+        Statement::Comment(
+            Location::new(&Span::new(&13,&0), &Position::new(&0,&13), &Position::new(&0,&0)),
+            " A note.".to_string()
+        )
+    );
+    let mut generator: HTMLCodeGenerator = HTMLCodeGenerator::new(&true, &false, &statements)
+        .expect("Could not create generator.");
+    assert_eq!(
+        generator.generate().expect("Could not generate HTML."),
+        "<p>Some <b>text</b> here. </p>"
+    );
+    let mut generator: HTMLCodeGenerator = HTMLCodeGenerator::new(&true, &false, &statements)
+        .expect("Could not create generator.")
+        .with_comments(&true);
+    assert_eq!(
+        generator.generate().expect("Could not generate HTML."),
+        "<!-- A note.--><p>Some <b>text</b><!-- a\nblock --> here. <!-- tail--></p><!--\n\n-->"
+    );
+}
+
+/// A function to test that inline
+/// code and quotes are lexed verbatim.
+#[test]
+pub fn test_verbatim_lexer(){
+    let source: &str = "Use <a * b $ (c)> or >(a ( quote $ <3)< now.";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let types: Vec<TokenType> = tokens
+        .iter()
+        .map(|token| token.token_type.clone())
+        .collect::<Vec<TokenType>>();
+    assert_eq!(
+        types,
+        vec![
+            TokenType::UserString,
+            TokenType::OpenAngle,
+            TokenType::RawString,
+            TokenType::CloseAngle,
+            TokenType::UserString,
+            TokenType::OpenQuote,
+            TokenType::RawString,
+            TokenType::CloseQuote,
+            TokenType::UserString
+        ]
+    );
+    let streamed: Vec<Token> = Lexer::from_string(source)
+        .collect::<Result<Vec<Token>, JiraiErr>>()
+        .expect("Could not tokenize string.");
+    assert_eq!(streamed, tokens);
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(
+        statements,
+        vec![
+            Statement::Paragraph(
+                Location::new(&Span::new(&44,&0), &Position::new(&0,&44), &Position::new(&0,&0)),
+                vec![
+                    InlineStatement::Text(
+                        Location::new(&Span::new(&4,&0), &Position::new(&0,&4), &Position::new(&0,&0)),
+                        "Use ".to_string()
+                    ),
+                    InlineStatement::Code(
+                        Location::new(&Span::new(&17,&4), &Position::new(&0,&17), &Position::new(&0,&4)),
+                        "a * b $ (c)".to_string()
+                    ),
+                    InlineStatement::Text(
+                        Location::new(&Span::new(&21,&17), &Position::new(&0,&21), &Position::new(&0,&17)),
+                        " or ".to_string()
+                    ),
+                    InlineStatement::Quote(
+                        Location::new(&Span::new(&39,&21), &Position::new(&0,&39), &Position::new(&0,&21)),
+                        "a ( quote $ <3".to_string()
+                    ),
+                    InlineStatement::Text(
+                        Location::new(&Span::new(&44,&39), &Position::new(&0,&44), &Position::new(&0,&39)),
+                        " now.".to_string()
+                    )
+                ]
+            )
+        ]
+    );
+    assert!(tokenize_string("<a * b\n>").is_err());
+    assert!(tokenize_string(">(a quote").is_err());
+    assert_eq!(
+        to_html("Code <<b> & >(\"a\" <i>)< here", &true, &false, &SourceType::Slice),
+        Ok("<p>Code <code>&lt;b</code> &amp; <q>&quot;a&quot; &lt;i&gt;</q> here</p>".to_string())
+    );
+}
+
+/// Testing the lexer's recovery
+/// mode, which reports every
+/// lex error in one pass.
+#[test]
+pub fn test_recovering_lexer(){
+    let source: &str = "Bad\u{7} text\u{0}.\n<code\nAll (~.~( fine";
+    let recovered: RecoveredTokens = tokenize_recovering(source);
+    let types: Vec<TokenType> = recovered.tokens
+        .iter()
+        .map(|token| token.token_type.clone())
+        .collect::<Vec<TokenType>>();
+    assert_eq!(
+        types,
+        vec![
+            TokenType::UserString,
+            TokenType::Error,
+            TokenType::UserString,
+            TokenType::Error,
+            TokenType::UserString,
+            TokenType::NewLine,
+            TokenType::OpenAngle,
+            TokenType::Error,
+            TokenType::NewLine,
+            TokenType::UserString,
+            TokenType::Error,
+            TokenType::UserString
+        ]
+    );
+    let starts: Vec<String> = recovered.errors
+        .iter()
+        .map(|error| error.start.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(
+        starts,
+        vec![
+            "0:3".to_string(),
+            "0:9".to_string(),
+            "1:13".to_string(),
+            "2:22".to_string()
+        ]
+    );
+    assert_eq!(recovered.tokens[7].value, Some("code".to_string()));
+    for (error, index) in recovered.errors.iter().zip([1, 3, 7, 10]){
+        assert_eq!(recovered.tokens[index].span, error.span);
+    }
+    assert_eq!(
+        tokenize_string(source).expect_err("Expected an error."),
+        JiraiErr::from(recovered.errors[0].clone())
+    );
+    let clean: RecoveredTokens = tokenize_recovering("<3 Hello $there$!");
+    assert!(clean.errors.is_empty());
+    assert_eq!(
+        clean.tokens,
+        tokenize_string("<3 Hello $there$!").expect("Could not tokenize string.")
+    );
+    assert_eq!(tokenize_recovering("").errors.len(), 1);
+}
+
+/// Testing the parser's recovery
+/// mode, which skips broken lines
+/// and keeps everything else.
+#[test]
+pub fn test_recovering_parser(){
+    let source: &str = "<3 Title\nA {#[broken link\n\n*Bold* text.\n\n$open italic\n~ Item";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let recovered: RecoveredStatements = parser.parse_recovering();
+    assert_eq!(
+        recovered.statements,
+        vec![
+            Statement::Heading(
+                Location::new(&Span::new(&8,&0), &Position::new(&0,&8), &Position::new(&0,&0)),
+                1,
+                vec![
+                    InlineStatement::Text(
+                        Location::new(&Span::new(&8,&2), &Position::new(&0,&8), &Position::new(&0,&2)),
+                        " Title".to_string()
+                    )
+                ]
+            ),
+            Statement::Paragraph(
+                Location::new(&Span::new(&39,&27), &Position::new(&3,&39), &Position::new(&3,&27)),
+                vec![
+                    InlineStatement::BoldText(
+                        Location::new(&Span::new(&33,&27), &Position::new(&3,&33), &Position::new(&3,&27)),
+                        Box::new(
+                            vec![
+                                InlineStatement::Text(
+                                    Location::new(&Span::new(&32,&28), &Position::new(&3,&32), &Position::new(&3,&28)),
+                                    "Bold".to_string()
+                                )
+                            ]
+                        )
+                    ),
+                    InlineStatement::Text(
+                        Location::new(&Span::new(&39,&33), &Position::new(&3,&39), &Position::new(&3,&33)),
+                        " text.".to_string()
+                    )
+                ]
+            ),
+            Statement::UnorderedList(
+                Location::new(&Span::new(&60,&54), &Position::new(&6,&60), &Position::new(&6,&54)),
+                vec![
+                    ListItem{
+                        location: Location::new(&Span::new(&60,&54), &Position::new(&6,&60), &Position::new(&6,&54)),
+                        task: None,
+                        contents: vec![
+                            InlineStatement::Text(
+                                Location::new(&Span::new(&60,&55), &Position::new(&6,&60), &Position::new(&6,&55)),
+                                " Item".to_string()
+                            )
+                        ],
+                        children: Vec::new()
+                    }
+                ]
+            )
+        ]
+    );
+    let starts: Vec<String> = recovered.errors
+        .iter()
+        .map(|error| error.start.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(starts, vec!["1:25".to_string(), "5:53".to_string()]);
+    let mut strict: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    assert_eq!(
+        strict.parse().expect_err("Expected an error."),
+        JiraiErr::from(recovered.errors[0].clone())
+    );
+    let lines: &str = "ok line\n*bad\n\nanother ok\n{#[x]\nlast";
+    let tokens: Vec<Token> = tokenize_string(lines)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let recovered: RecoveredStatements = parser.parse_recovering();
+    let kept: Vec<&str> = recovered.statements
+        .iter()
+        .map(|statement| statement.location().span.slice(lines).expect("Could not slice source."))
+        .collect::<Vec<&str>>();
+    assert_eq!(kept, vec!["ok line", "another ok", "last"]);
+    assert_eq!(recovered.errors.len(), 2);
+}
+
+/// Testing the envelopes of
+/// documents marked by the `(^-^)`
+/// symbol.
+#[test]
+pub fn test_document_envelope(){
+    let source: &str = "Preamble text.\n(^-^)\n<3 One\n(^-^)\nBetween.\n(^-^)\nTwo\n(^-^)\nAfter.";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Document, &tokens)
+        .expect("Could not create parser.");
+    let documents: Vec<Vec<Statement>> = parser.parse_documents()
+        .expect("Could not parse documents.");
+    assert_eq!(
+        documents,
+        vec![
+            vec![
+                Statement::Heading(
+                    Location::new(&Span::new(&27,&21), &Position::new(&2,&27), &Position::new(&2,&21)),
+                    1,
+                    vec![
+                        InlineStatement::Text(
+                            Location::new(&Span::new(&27,&23), &Position::new(&2,&27), &Position::new(&2,&23)),
+                            " One".to_string()
+                        )
+                    ]
+                )
+            ],
+            vec![
+                Statement::Paragraph(
+                    Location::new(&Span::new(&52,&49), &Position::new(&6,&52), &Position::new(&6,&49)),
+                    vec![
+                        InlineStatement::Text(
+                            Location::new(&Span::new(&52,&49), &Position::new(&6,&52), &Position::new(&6,&49)),
+                            "Two".to_string()
+                        )
+                    ]
+                )
+            ]
+        ]
+    );
+    assert!(parser.is_done());
+    let mut parser: Parser = Parser::new(&SourceType::Document, &tokens)
+        .expect("Could not create parser.");
+    assert_eq!(parser.parse(), Ok(documents.concat()));
+    let cases: Vec<(&str, &str)> = vec![
+        ("No envelope.", "Missing opening document limiter at position \"0:0\"!"),
+        ("(^-^)\nUnclosed.", "Missing closing document limiter for the document opened at position \"0:0\"!"),
+        ("(^-^)\nText (^-^)", "Misplaced document limiter at position \"1:11\"!"),
+        ("(^-^) Text\n(^-^)", "Misplaced document limiter at position \"0:0\"!")
+    ];
+    for (case, message) in cases {
+        let tokens: Vec<Token> = tokenize_string(case)
+            .expect("Could not tokenize string.");
+        let mut parser: Parser = Parser::new(&SourceType::Document, &tokens)
+            .expect("Could not create parser.");
+        assert_eq!(parser.parse(), Err(JiraiErr::new(message)));
+        let mut parser: Parser = Parser::new(&SourceType::Document, &tokens)
+            .expect("Could not create parser.");
+        let recovered: RecoveredStatements = parser.parse_recovering();
+        assert_eq!(recovered.errors[0].message, message);
+        assert_eq!(recovered.statements.len(), 1);
+    }
+    let tokens: Vec<Token> = tokenize_string("Text (^-^)")
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    assert_eq!(
+        parser.parse(),
+        Err(JiraiErr::new("Unexpected document limiter at position \"0:5\"!"))
+    );
+}
 
+/// Testing that consecutive lines
+/// form one paragraph, which ends
+/// at a blank line or at another
+/// block element.
+#[test]
+pub fn test_paragraphs(){
+    let source: &str = "First line\nsecond *line*\n\n\nNew paragraph\n<3 Heading\nAfter heading\n~ item";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 5);
+    assert_eq!(
+        statements[0].location().span.slice(source),
+        Ok("First line\nsecond *line*")
+    );
+    match &statements[0] {
+        Statement::Paragraph(_, inline_statements) => assert_eq!(
+            inline_statements[1],
+            InlineStatement::SoftBreak(
+                Location::new(&Span::new(&11,&10), &Position::new(&0,&11), &Position::new(&0,&10))
+            )
+        ),
+        _ => panic!("Expected a paragraph.")
+    };
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<p>First line second <b>line</b></p>",
+                "<p>New paragraph</p>",
+                "<h1> Heading</h1>",
+                "<p>After heading</p>",
+                "<ul><li> item</li></ul>"
+            ).to_string()
+        )
+    );
+    assert_eq!(
+        to_html("First line\nsecond *line*", &false, &false, &SourceType::Slice),
+        Ok("<p>First line\nsecond \n<b>line</b></p>".to_string())
+    );
+    assert_eq!(
+        to_html("*bold\nstill* and $italic\n  too$", &true, &false, &SourceType::Slice),
+        Ok("<p><b>bold still</b> and <i>italic too</i></p>".to_string())
+    );
+    let unclosed: Vec<Token> = tokenize_string("*bold\n\nnext")
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &unclosed)
+        .expect("Could not create parser.");
+    assert_eq!(
+        parser.parse(),
+        Err(JiraiErr::new("Unclosed bold text opened at position \"0:0\"!"))
+    );
+}
+
+/// Testing that consecutive list
+/// lines form one list and that
+/// items continue onto wrapped lines.
+#[test]
+pub fn test_lists(){
+    let source: &str = "~ One\n~ Two that\nwraps *here*\n~ Three\n\n~ Other list\nText \\~ ok";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 2);
+    let items: Vec<ListItem> = match &statements[0] {
+        Statement::UnorderedList(_, items) => items.clone(),
+        _ => panic!("Expected a list.")
+    };
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[1].location.span.slice(source), Ok("~ Two that\nwraps *here*"));
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<ul><li> One</li><li> Two that wraps <b>here</b></li><li> Three</li></ul>",
+                "<ul><li> Other list Text ~ ok</li></ul>"
+            ).to_string()
+        )
+    );
+    assert_eq!(
+        to_html("Not ~ a list", &true, &false, &SourceType::Slice),
+        Err(JiraiErr::new("Unexpected list marker at position \"0:4\"!"))
+    );
+}
+
+/// Testing that indented list
+/// lines form nested lists.
+#[test]
+pub fn test_nested_lists(){
+    let source: &str = "~ A\n  ~ A1\n    ~ A1a\n    wrapped\n  ~ A2\n~ B\n\t~ B1\n\nText";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens[3].token_type, TokenType::Indent);
+    assert_eq!(tokens[3].value, Some("  ".to_string()));
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 2);
+    let items: Vec<ListItem> = match &statements[0] {
+        Statement::UnorderedList(_, items) => items.clone(),
+        _ => panic!("Expected a list.")
+    };
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].location.span.slice(source), Ok("~ A"));
+    match &items[0].children[0] {
+        Statement::UnorderedList(location, nested) => {
+            assert_eq!(location.span.slice(source), Ok("~ A1\n    ~ A1a\n    wrapped\n  ~ A2"));
+            assert_eq!(nested.len(), 2);
+            assert_eq!(nested[0].children.len(), 1);
+        },
+        _ => panic!("Expected a nested list.")
+    };
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<ul><li> A<ul><li> A1<ul><li> A1a wrapped</li></ul></li><li> A2</li></ul></li>",
+                "<li> B<ul><li> B1</li></ul></li></ul>",
+                "<p>Text</p>"
+            ).to_string()
+        )
+    );
+    assert_eq!(
+        to_html("  ~ Indented\n~ Outdented", &true, &false, &SourceType::Slice),
+        Ok("<ul><li> Indented</li><li> Outdented</li></ul>".to_string())
+    );
+    assert_eq!(
+        to_html("~ a\n   ~ b\n  ~ c\n~ d", &true, &false, &SourceType::Slice),
+        Ok("<ul><li> a<ul><li> b</li><li> c</li></ul></li><li> d</li></ul>".to_string())
+    );
+    assert_eq!(
+        to_html("Para\n  continued\n  <3 Head", &true, &false, &SourceType::Slice),
+        Ok("<p>Para continued</p><h1> Head</h1>".to_string())
+    );
+}
+
+/// Testing ordered lists with
+/// automatic and explicit numbering
+/// and lists of both kinds nested
+/// inside each other.
+#[test]
+pub fn test_ordered_lists(){
+    let source: &str = "#~ Mix\n#~ Bake\n  ~ At 180 degrees\n  3~ Third\n  #~ Fourth\n~ Serve\n\nIn 2024.";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens[0].token_type, TokenType::OrderedListMarker);
+    assert_eq!(tokens[0].value, Some("#".to_string()));
+    assert_eq!(tokens[11].token_type, TokenType::OrderedListMarker);
+    assert_eq!(tokens[11].value, Some("3".to_string()));
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 3);
+    assert!(matches!(statements[0], Statement::OrderedList(_, 1, _)));
+    assert!(matches!(statements[1], Statement::UnorderedList(_, _)));
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<ol><li> Mix</li><li> Bake",
+                "<ul><li> At 180 degrees</li></ul>",
+                "<ol start=\"3\"><li> Third</li><li> Fourth</li></ol></li></ol>",
+                "<ul><li> Serve</li></ul>",
+                "<p>In 2024.</p>"
+            ).to_string()
+        )
+    );
+    let tokens: Vec<Token> = tokenize_string("In 2024~")
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens[1].token_type, TokenType::ListMarker);
+    assert_eq!(
+        to_html("99999999999999999999999~ Big", &true, &false, &SourceType::Slice),
+        Err(JiraiErr::new("Invalid list number at position \"0:0\"!"))
+    );
+}
+
+/// Testing task items, their
+/// checkboxes, and the collection
+/// of open tasks.
+#[test]
+pub fn test_task_lists(){
+    let source: &str = "~ [x] Done\n~ [ ] Open\n  #~ [X] Nested done\n  #~[ ] Nested open\n~ Plain";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    let tasks: Vec<ListItem> = collect_open_tasks(&statements);
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].location.start, Position::new(&1, &11));
+    assert_eq!(tasks[0].location.span.slice(source), Ok("~ [ ] Open"));
+    assert_eq!(tasks[1].location.start, Position::new(&3, &45));
+    assert_eq!(
+        tasks[1].contents,
+        vec![
+            InlineStatement::Text(
+                Location::new(&Span::new(&62,&50), &Position::new(&3,&62), &Position::new(&3,&50)),
+                " Nested open".to_string()
+            )
+        ]
+    );
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<ul><li><input type=\"checkbox\" checked disabled/> Done</li>",
+                "<li><input type=\"checkbox\" disabled/> Open",
+                "<ol><li><input type=\"checkbox\" checked disabled/> Nested done</li>",
+                "<li><input type=\"checkbox\" disabled/> Nested open</li></ol></li>",
+                "<li> Plain</li></ul>"
+            ).to_string()
+        )
+    );
+    assert_eq!(
+        to_html("~ [y] no\n~ [] *empty*\n~ [ab]", &true, &false, &SourceType::Slice),
+        Ok("<ul><li> [y] no</li><li> [] <b>empty</b></li><li> [ab]</li></ul>".to_string())
+    );
+}
+
+/// Testing the parsing and
+/// code generation for block
+/// quotes.
+#[test]
+pub fn test_block_quotes(){
+    let source: &str = ">(\nSo it *begins*.\n~ [ ] Read\n>(\nInner\n)<\n)< $Alyx$\nA >(short)< quote.";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not parse string.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[0].location().span.slice(source), Ok(&source[..51]));
+    assert_eq!(collect_open_tasks(&statements).len(), 1);
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<blockquote><p>So it <b>begins</b>.</p>",
+                "<ul><li><input type=\"checkbox\" disabled/> Read</li></ul>",
+                "<blockquote><p>Inner</p></blockquote>",
+                "<cite> <i>Alyx</i></cite></blockquote>",
+                "<p>A <q>short</q> quote.</p>"
+            ).to_string()
+        )
+    );
+    let unclosed: Vec<Token> = tokenize_string(">(\nNever closed")
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &unclosed)
+        .expect("Could not create parser.");
+    assert!(parser.parse().is_err());
+    let unclosed: Vec<Token> = tokenize_string(">(\nquoted para\n\nmore")
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &unclosed)
+        .expect("Could not create parser.");
+    let recovered: RecoveredStatements = parser.parse_recovering();
+    assert_eq!(
+        recovered.statements.iter().map(|statement| statement.location().span.clone()).collect::<Vec<Span>>(),
+        vec![Span::new(&14,&3), Span::new(&20,&16)]
+    );
+    assert_eq!(
+        recovered.errors.iter().map(|error| error.message.clone()).collect::<Vec<String>>(),
+        vec!["Unclosed block quote opened at position \"0:0\"!".to_string()]
+    );
+}
+
+/// Testing the lexing, parsing,
+/// and code generation for fenced
+/// code blocks.
+#[test]
+pub fn test_code_blocks(){
+    let source: &str = "<<<rust\nfn main(){\n\n    <3 *x* $\n}\n>>>\n<<<\n>>>";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    assert_eq!(
+        tokens.iter().map(|token| token.token_type.clone()).collect::<Vec<TokenType>>(),
+        vec![
+            TokenType::OpenFence,
+            TokenType::RawString,
+            TokenType::NewLine,
+            TokenType::CloseFence,
+            TokenType::NewLine,
+            TokenType::OpenFence,
+            TokenType::CloseFence
+        ]
+    );
+    assert_eq!(tokens[0].value, Some("rust".to_string()));
+    assert_eq!(tokens[1].value, Some("fn main(){\n\n    <3 *x* $\n}".to_string()));
+    assert_eq!(tokens[3].start, Position::new(&5, &35));
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[1], Statement::CodeBlock(
+        Location::new(&Span::new(&46,&39), &Position::new(&7,&46), &Position::new(&6,&39)),
+        None,
+        String::new()
+    ));
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<pre><code class=\"language-rust\">fn main(){\n\n    &lt;3 *x* $\n}</code></pre>",
+                "<pre><code></code></pre>"
+            ).to_string()
+        )
+    );
+    let mut document: LexedDocument = LexedDocument::new(source)
+        .expect("Could not tokenize string.");
+    document.apply_edit(&TextEdit::new(&Span::new(&20, &20), "(^-^)"))
+        .expect("Could not apply edit.");
+    assert_eq!(Ok(document.tokens), tokenize_string(&document.source));
+    assert!(tokenize_string("<<<rust\nfn main(){}").is_err());
+    assert_eq!(
+        to_html("<<<a b\nx\n>>>", &true, &false, &SourceType::Slice),
+        Ok("<pre><code class=\"language-a\">x</code></pre>".to_string())
+    );
+}
+
+/// Testing the parsing and
+/// code generation for tables
+/// and the diagnostics for their
+/// ragged rows.
+#[test]
+pub fn test_tables(){
+    let source: &str = "| Name | *Price* | Note\n|:--|--:|:-:|\n| Bow | 3 |  \n| Lace \\| silk | 5 | $new$ | x |";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let recovered: RecoveredStatements = parser.parse_recovering();
+    assert_eq!(recovered.statements.len(), 1);
+    assert_eq!(
+        recovered.errors,
+        vec![
+            Diagnostic::new(
+                &Span::new(&51,&38),
+                &Position::new(&2,&51),
+                &Position::new(&2,&38),
+                "Table row at position \"2:38\" has 2 cells instead of 3!"
+            ),
+            Diagnostic::new(
+                &Span::new(&84,&52),
+                &Position::new(&3,&84),
+                &Position::new(&3,&52),
+                "Table row at position \"3:52\" has 4 cells instead of 3!"
+            )
+        ]
+    );
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<table><thead><tr><th style=\"text-align:left\">Name</th>",
+                "<th style=\"text-align:right\"><b>Price</b></th>",
+                "<th style=\"text-align:center\">Note</th></tr></thead>",
+                "<tbody><tr><td style=\"text-align:left\">Bow</td>",
+                "<td style=\"text-align:right\">3</td>",
+                "<td style=\"text-align:center\"></td></tr>",
+                "<tr><td style=\"text-align:left\">Lace | silk</td>",
+                "<td style=\"text-align:right\">5</td>",
+                "<td style=\"text-align:center\"><i>new</i></td></tr></tbody></table>"
+            ).to_string()
+        )
+    );
+    let missing: Vec<Token> = tokenize_string("| Name |\n| Bow |")
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &missing)
+        .expect("Could not create parser.");
+    assert!(parser.parse().is_err());
+    assert_eq!(
+        to_html("a | b\n\n| a | b |\n|--|--|\nc | d", &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<p>a | b</p><table><thead><tr><th>a</th><th>b</th></tr></thead>",
+                "<tbody></tbody></table><p>c | d</p>"
+            ).to_string()
+        )
+    );
+}
+
+/// Testing the parsing and
+/// code generation for dividers.
+#[test]
+pub fn test_dividers(){
+    let source: &str = "Before.\n</3</3</3 \nAfter <</3>.";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(
+        statements[1],
+        Statement::Divider(
+            Location::new(&Span::new(&18,&8), &Position::new(&1,&18), &Position::new(&1,&8))
+        )
+    );
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok("<p>Before.</p><hr/><p>After <code>&lt;/3</code>.</p>".to_string())
+    );
+    let mut generator: HTMLCodeGenerator = HTMLCodeGenerator::new(&true, &false, &statements)
+        .expect("Could not create generator.")
+        .with_divider_decoration("🎀 🎀 🎀");
+    assert_eq!(
+        generator.generate().expect("Could not generate HTML."),
+        concat!(
+            "<p>Before.</p><div class=\"jirai-divider\" role=\"separator\">🎀 🎀 🎀</div>",
+            "<p>After <code>&lt;/3</code>.</p>"
+        )
+    );
+}
+
+/// Testing the parsing and
+/// code generation for footnotes
+/// and the diagnostics for undefined
+/// and unused footnotes.
+#[test]
+pub fn test_footnotes(){
+    let source: &str = "Ribbons{*[b]} and *lace{*[a]}*{*[b]}{*[x]}.\n{*[a]}: Cotton.\n{*[b]}: Silk\nand satin.\n{*[c]}: Unused.";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 4);
+    assert_eq!(
+        statements[1],
+        Statement::FootnoteDefinition(
+            Location::new(&Span::new(&59,&44), &Position::new(&1,&59), &Position::new(&1,&44)),
+            "a".to_string(),
+            vec![
+                InlineStatement::Text(
+                    Location::new(&Span::new(&59,&51), &Position::new(&1,&59), &Position::new(&1,&51)),
+                    " Cotton.".to_string()
+                )
+            ]
+        )
+    );
+    let mut generator: HTMLCodeGenerator = HTMLCodeGenerator::new(&true, &false, &statements)
+        .expect("Could not create generator.");
+    assert_eq!(
+        generator.generate().expect("Could not generate HTML."),
+        concat!(
+            "<p>Ribbons<sup id=\"fnref-1\"><a href=\"#fn-1\" role=\"doc-noteref\">1</a></sup> and ",
+            "<b>lace<sup id=\"fnref-2\"><a href=\"#fn-2\" role=\"doc-noteref\">2</a></sup></b>",
+            "<sup id=\"fnref-1-2\"><a href=\"#fn-1\" role=\"doc-noteref\">1</a></sup><sup>[x]</sup>.</p>",
+            "<section class=\"footnotes\" role=\"doc-endnotes\"><ol>",
+            "<li id=\"fn-1\"> Silk and satin. <a href=\"#fnref-1\" role=\"doc-backlink\">↩</a>",
+            " <a href=\"#fnref-1-2\" role=\"doc-backlink\">↩</a></li>",
+            "<li id=\"fn-2\"> Cotton. <a href=\"#fnref-2\" role=\"doc-backlink\">↩</a></li>",
+            "</ol></section>"
+        )
+    );
+    assert_eq!(
+        generator.diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.clone())
+            .collect::<Vec<String>>(),
+        vec![
+            "Unused footnote \"c\" defined at position \"4:84\"!".to_string(),
+            "Undefined footnote \"x\" referenced at position \"0:36\"!".to_string()
+        ]
+    );
+    assert_eq!(
+        to_html("A{*[a]} B{*[b]}.\n{*[a]}: One.\n{*[b]}: See{*[a]}.", &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<p>A<sup id=\"fnref-1\"><a href=\"#fn-1\" role=\"doc-noteref\">1</a></sup> ",
+                "B<sup id=\"fnref-2\"><a href=\"#fn-2\" role=\"doc-noteref\">2</a></sup>.</p>",
+                "<section class=\"footnotes\" role=\"doc-endnotes\"><ol>",
+                "<li id=\"fn-1\"> One. <a href=\"#fnref-1\" role=\"doc-backlink\">↩</a>",
+                " <a href=\"#fnref-1-2\" role=\"doc-backlink\">↩</a></li>",
+                "<li id=\"fn-2\"> See<sup id=\"fnref-1-2\"><a href=\"#fn-1\" role=\"doc-noteref\">1</a></sup>.",
+                " <a href=\"#fnref-2\" role=\"doc-backlink\">↩</a></li>",
+                "</ol></section>"
+            ).to_string()
+        )
+    );
+}
+
+/// Testing the parsing and
+/// code generation for definition
+/// lists.
+#[test]
+pub fn test_definition_lists(){
+    let source: &str = "; *Jirai* kei\n: A fashion style.\n: A {#[link][https://alyxshang.boo]}\n  that wraps.\n; <dl>\n: $Definition$ list.\n\n: Not a definition.";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    assert_eq!(statements.len(), 2);
+    let items: Vec<DefinitionItem> = match &statements[0] {
+        Statement::DefinitionList(_, items) => items.clone(),
+        _ => Vec::new()
+    };
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].definitions.len(), 2);
+    assert_eq!(items[0].location.span.slice(source), Ok(&source[..83]));
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<dl><dt> <b>Jirai</b> kei</dt><dd> A fashion style.</dd>",
+                "<dd> A <a href=\"https://alyxshang.boo\">link</a> that wraps.</dd>",
+                "<dt> <code>dl</code></dt><dd> <i>Definition</i> list.</dd></dl>",
+                "<p>: Not a definition.</p>"
+            ).to_string()
+        )
+    );
+    assert_eq!(
+        to_html("The ratio is\n: three to one", &true, &false, &SourceType::Slice),
+        Ok("<p>The ratio is : three to one</p>".to_string())
+    );
+    assert_eq!(
+        to_html("Item\n; not a term\n\n~ a\n; b", &true, &false, &SourceType::Slice),
+        Ok("<p>Item ; not a term</p><ul><li> a ; b</li></ul>".to_string())
+    );
+    assert_eq!(
+        to_html("Text\n; Term\n: Definition", &true, &false, &SourceType::Slice),
+        Ok("<p>Text</p><dl><dt> Term</dt><dd> Definition</dd></dl>".to_string())
+    );
+}
+
+/// Testing the lexing, parsing,
+/// and code generation for struck
+/// through, underlined, and highlighted
+/// text.
+#[test]
+pub fn test_text_styles(){
+    let source: &str = "~~old *and ==new==*~~ __under $lined$__ a\\_\\_b\\=\\=c";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    assert_eq!(tokens[0].token_type, TokenType::StrikethroughText);
+    assert_eq!(tokens[4].token_type, TokenType::HighlightText);
+    assert_eq!(tokens[10].token_type, TokenType::UnderlineText);
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    let contents: Vec<InlineStatement> = match &statements[0] {
+        Statement::Paragraph(_, contents) => contents.clone(),
+        _ => Vec::new()
+    };
+    assert_eq!(contents[0].location().span.slice(source), Ok("~~old *and ==new==*~~"));
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<p><del>old <b>and <mark>new</mark></b></del> ",
+                "<u>under <i>lined</i></u> a__b==c</p>"
+            ).to_string()
+        )
+    );
+    let plain: Vec<(&str, &str)> = vec![
+        ("1 + 1 == 2", "<p>1 + 1 == 2</p>"),
+        ("x ~~ y", "<p>x ~~ y</p>"),
+        ("x ==y", "<p>x ==y</p>"),
+        ("~~a", "<p>~~a</p>"),
+        ("==never closed", "<p>==never closed</p>"),
+        ("call __init__ now", "<p>call __init__ now</p>"),
+        ("a ==b\nc== d", "<p>a ==b c== d</p>")
+    ];
+    for (source, html) in plain {
+        assert_eq!(
+            to_html(source, &true, &false, &SourceType::Slice),
+            Ok(html.to_string())
+        );
+    }
+    let crossed: Vec<Token> = tokenize_string("a ~~b __c~~ d__")
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &crossed)
+        .expect("Could not create parser.");
+    assert_eq!(
+        parser.parse(),
+        Err(JiraiErr::new("Unclosed underlined text opened at position \"0:13\"!"))
+    );
+}
+
+/// Testing the parsing and
+/// code generation for superscript,
+/// subscript, keyboard input, and
+/// sample output.
+#[test]
+pub fn test_text_elements(){
+    let source: &str = "H{_[2]}O is 1{^[st]}. Press {+[Ctrl+Shift+P]} or {+[Ctrl++]} or {+[Enter]} to see {=[Done.]}";
+    let tokens: Vec<Token> = tokenize_string(source)
+        .expect("Could not tokenize string.");
+    let mut parser: Parser = Parser::new(&SourceType::Slice, &tokens)
+        .expect("Could not create parser.");
+    let statements: Vec<Statement> = parser.parse()
+        .expect("Could not parse string.");
+    let contents: Vec<InlineStatement> = match &statements[0] {
+        Statement::Paragraph(_, contents) => contents.clone(),
+        _ => Vec::new()
+    };
+    assert_eq!(
+        contents[1],
+        InlineStatement::Subscript(
+            Location::new(&Span::new(&7,&1), &Position::new(&0,&7), &Position::new(&0,&1)),
+            "2".to_string()
+        )
+    );
+    assert_eq!(
+        contents[5],
+        InlineStatement::Keyboard(
+            Location::new(&Span::new(&45,&28), &Position::new(&0,&45), &Position::new(&0,&28)),
+            vec!["Ctrl".to_string(), "Shift".to_string(), "P".to_string()]
+        )
+    );
+    assert_eq!(
+        to_html(source, &true, &false, &SourceType::Slice),
+        Ok(
+            concat!(
+                "<p>H<sub>2</sub>O is 1<sup>st</sup>. Press ",
+                "<kbd><kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>P</kbd></kbd> or ",
+                "<kbd><kbd>Ctrl</kbd>+<kbd>+</kbd></kbd> or <kbd>Enter</kbd> ",
+                "to see <samp>Done.</samp></p>"
+            ).to_string()
+        )
+    );
+}
+
+/// A function to test that a single
+/// line of more than a hundred
+/// kilobytes is lexed in full.
+#[test]
+pub fn test_long_line_lexer(){
+    let source: String = "ab *c* <d> >(e)< ".repeat(8000);
+    let tokens: Vec<Token> = tokenize_string(&source)
+        .expect("Could not tokenize string.");
+    assert!(source.len() > 100_000);
+    assert_eq!(tokens.len(), 8000 * 12 + 1);
 }